@@ -67,10 +67,19 @@ fn main() {
         init.push(format!(
             "add_template({:?}, {:?})",
             name,
-            &markdown_to_html(&explanation.info, &ComrakOptions::default())
+            render_template(&explanation.info)
         ));
     }
 
+    // Partials are only rendered through `{{ call }}` tags, from within a paragraph
+    for (name, partial) in config.partial {
+        let rendered = render_template(&partial.info);
+        let stripped = rendered
+            .trim_start_matches("<p>")
+            .trim_end_matches("</p>\n");
+        init.push(format!("add_template({:?}, {:?})", name, stripped));
+    }
+
     let mut source = String::new();
 
     source.push_str(
@@ -107,6 +116,22 @@ fn main() {
 #[derive(Deserialize)]
 struct Config {
     help: BTreeMap<String, Explanation>,
+    #[serde(default)]
+    partial: BTreeMap<String, Partial>,
+}
+
+#[derive(Deserialize)]
+struct Partial {
+    info: String,
+}
+
+/// Renders the markdown of a template. Values in link destinations, like
+/// `[docs](https://doc.rust-lang.org/std/{path})`, are percent-encoded by the renderer, so
+/// they are decoded back into template values
+fn render_template(info: &str) -> String {
+    markdown_to_html(info, &ComrakOptions::default())
+        .replace("%7B", "{")
+        .replace("%7D", "}")
 }
 
 #[derive(Deserialize)]
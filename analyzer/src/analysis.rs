@@ -1,6 +1,7 @@
 use crate::help::*;
 use crate::ir::{Location, NodeId, NodeMap, Owner, Range};
 use crate::syn_wrappers::{Comment, Syn, SynKind};
+pub use exits::ExitPoint;
 use generics::Generics;
 use proc_macro2::{LineColumn, Span};
 use quote::ToTokens;
@@ -9,6 +10,7 @@ use std::{
     rc::Rc,
 };
 use syn::spanned::Spanned;
+pub use unsafety::UnsafeOperation;
 
 macro_rules! token {
     ($self:expr, $token:expr, $item:ident) => {
@@ -45,12 +47,27 @@ macro_rules! get_ancestor {
     };
 }
 
+mod captures;
+mod casts;
+mod const_eval;
+mod desugaring;
+mod elision;
+mod exits;
 mod expressions;
 mod generics;
 mod items;
 mod nested_items;
 mod patterns;
+mod precedence;
+mod printing;
+mod privacy;
+mod question_mark;
+mod resolution;
+mod scopes;
+mod traits;
 mod types;
+mod unsafety;
+mod visibility;
 
 #[derive(Default)]
 pub struct ExplorationState {
@@ -90,6 +107,7 @@ struct NodeAnalyzer<'a> {
     ancestors: &'a [(NodeId, Syn<'a>)],
     generics_state: &'a mut GenericsState,
     help: Option<(Range, HelpItem)>,
+    desugared: Option<String>,
 }
 
 #[derive(Default)]
@@ -121,6 +139,8 @@ pub struct AnalysisResult {
     pub start: Location,
     pub end: Location,
     pub help: HelpItem,
+    /// Equivalent code for syntactic sugar
+    pub desugared: Option<String>,
 }
 
 impl Analyzer {
@@ -164,6 +184,7 @@ impl Analyzer {
                 help: HelpItem::Comment {
                     block: comment.block,
                 },
+                desugared: None,
             }))
         } else {
             None
@@ -229,25 +250,125 @@ impl Analyzer {
             .next()
     }
 
-    fn analyze_node(&self, id: NodeId, location: Location) -> Option<AnalysisResult> {
-        let ancestors = {
-            let mut ancestors = vec![];
-            let mut id = id;
-
-            while let Some(node) = self.node_map.get(id) {
-                ancestors.push((id, unsafe { node.element.as_syn() }));
-                id = if let Some(parent_id) = node.parent() {
-                    parent_id
-                } else {
-                    break;
+    /// The range where the binding whose name is at `location` can be used, to be highlighted
+    /// along with its explanation
+    pub fn binding_scope(&self, location: Location) -> Option<Range> {
+        let ancestors = self.ancestors_at(location)?;
+        let idx = ancestors.iter().rposition(|(_, node)| match node {
+            Syn::PatIdent(pat_ident) => {
+                let span = pat_ident.ident.span();
+                Location::from(span.start()) <= location && location <= span.end().into()
+            }
+            _ => false,
+        })?;
+        let pat_ident = match ancestors[idx].1 {
+            Syn::PatIdent(pat_ident) => pat_ident,
+            _ => return None,
+        };
+
+        let mut generics_state = Default::default();
+        let mut node_analyzer =
+            NodeAnalyzer::new(ancestors[idx].0, location, &self, &mut generics_state);
+        node_analyzer.ancestors = &ancestors[..idx];
+        node_analyzer
+            .binding_scope(pat_ident)
+            .map(|scope| scope.range)
+    }
+
+    /// Every point where control leaves the function whose `fn` keyword or `->` arrow is at
+    /// `location`, to be highlighted along with its explanation
+    pub fn exit_points(&self, location: Location) -> Vec<ExitPoint> {
+        let ancestors = self.ancestors_at(location).unwrap_or_default();
+        let mut generics_state = Default::default();
+
+        for (idx, &(id, node)) in ancestors.iter().enumerate().rev() {
+            if let Syn::ItemFn(..) | Syn::ImplItemMethod(..) | Syn::TraitItemMethod(..) = node {
+                let mut node_analyzer = NodeAnalyzer::new(id, location, &self, &mut generics_state);
+                node_analyzer.ancestors = &ancestors[..idx];
+                return node_analyzer.exit_points(node).unwrap_or_default();
+            }
+        }
+
+        vec![]
+    }
+
+    /// Every operation that requires the `unsafe` block or `unsafe fn` whose `unsafe` keyword is
+    /// at `location`, to be highlighted along with its explanation
+    pub fn unsafe_operations(&self, location: Location) -> Vec<UnsafeOperation> {
+        let ancestors = self.ancestors_at(location).unwrap_or_default();
+        let mut generics_state = Default::default();
+
+        for (idx, &(id, node)) in ancestors.iter().enumerate().rev() {
+            if let Syn::ExprUnsafe(..)
+            | Syn::ItemFn(..)
+            | Syn::ImplItemMethod(..)
+            | Syn::TraitItemMethod(..) = node
+            {
+                let mut node_analyzer = NodeAnalyzer::new(id, location, &self, &mut generics_state);
+                node_analyzer.ancestors = &ancestors[..idx];
+                if let Some(operations) = node_analyzer.unsafe_operations(node) {
+                    return operations;
                 }
             }
+        }
 
-            ancestors.reverse();
+        vec![]
+    }
 
-            ancestors
+    /// The operands of the binary operator at `location`, to be highlighted along with its
+    /// explanation
+    pub fn operands(&self, location: Location) -> Vec<Range> {
+        let ancestors = self.ancestors_at(location).unwrap_or_default();
+        let idx = ancestors.iter().rposition(|(_, node)| match node {
+            Syn::BinOp(bin_op) => {
+                let span = bin_op.span();
+                Location::from(span.start()) <= location && location <= span.end().into()
+            }
+            _ => false,
+        });
+        let idx = match idx {
+            Some(idx) => idx,
+            None => return vec![],
         };
 
+        let mut generics_state = Default::default();
+        let mut node_analyzer =
+            NodeAnalyzer::new(ancestors[idx].0, location, &self, &mut generics_state);
+        node_analyzer.ancestors = &ancestors[..idx];
+        node_analyzer.operands().unwrap_or_default()
+    }
+
+    fn ancestors_at(&self, location: Location) -> Option<Vec<(NodeId, Syn<'_>)>> {
+        let loc_idx = self
+            .locations
+            .binary_search_by(|(_, range)| range.0.cmp(&location))
+            .unwrap_or_else(|err| err.saturating_sub(1));
+        let (id, _) = self.locations.get(loc_idx).cloned()?;
+
+        Some(self.ancestors(id))
+    }
+
+    fn ancestors(&self, id: NodeId) -> Vec<(NodeId, Syn<'_>)> {
+        let mut ancestors = vec![];
+        let mut id = id;
+
+        while let Some(node) = self.node_map.get(id) {
+            ancestors.push((id, unsafe { node.element.as_syn() }));
+            id = if let Some(parent_id) = node.parent() {
+                parent_id
+            } else {
+                break;
+            }
+        }
+
+        ancestors.reverse();
+
+        ancestors
+    }
+
+    fn analyze_node(&self, id: NodeId, location: Location) -> Option<AnalysisResult> {
+        let ancestors = self.ancestors(id);
+
         let mut generics_state = Default::default();
 
         for (idx, &(node_id, node)) in ancestors.iter().enumerate() {
@@ -258,6 +379,7 @@ impl Analyzer {
             node_analyzer.analyze_node_first_pass(node);
 
             if node_analyzer.help.is_some() {
+                node_analyzer.desugar(node);
                 return node_analyzer.result();
             }
         }
@@ -269,6 +391,7 @@ impl Analyzer {
             node_analyzer.analyze_node(node);
 
             if node_analyzer.help.is_some() {
+                node_analyzer.desugar(node);
                 return node_analyzer.result();
             }
         }
@@ -335,19 +458,26 @@ impl<'a> NodeAnalyzer<'a> {
             analyzer,
             ancestors: &[],
             help: None,
+            desugared: None,
             generics_state,
         }
     }
 
     fn result(self) -> Option<AnalysisResult> {
-        self.help
-            .map(|((start, end), help)| AnalysisResult { start, end, help })
+        let desugared = self.desugared;
+        self.help.map(|((start, end), help)| AnalysisResult {
+            start,
+            end,
+            help,
+            desugared,
+        })
     }
 
     fn analyze_node_first_pass(&mut self, node: Syn) {
         match node {
             Syn::BoundLifetimes(i) => self.visit_bound_lifetimes_first_pass(i),
             Syn::ExprForLoop(i) => self.visit_expr_for_loop_first_pass(i),
+            Syn::FnArg(i) => self.visit_fn_arg_first_pass(i),
             Syn::ItemEnum(i) => self.visit_item_enum_first_pass(i),
             Syn::ItemFn(i) => self.visit_item_fn_first_pass(i),
             Syn::ItemImpl(i) => self.visit_item_impl_first_pass(i),
@@ -361,6 +491,7 @@ impl<'a> NodeAnalyzer<'a> {
             Syn::TraitItemMethod(i) => self.visit_trait_item_method_first_pass(i),
             Syn::TypeBareFn(i) => self.visit_type_bare_fn_first_pass(i),
             Syn::Local(i) => self.visit_local_first_pass(i),
+            Syn::PatLit(i) => self.visit_pat_lit_first_pass(i),
             Syn::VisRestricted(i) => self.visit_vis_restricted_first_pass(i),
             _ => {}
         }
@@ -379,10 +510,10 @@ impl<'a> NodeAnalyzer<'a> {
             Syn::BareFnArg(_i) => { /* self.visit_bare_fn_arg(i) */ }
             Syn::BinOp(i) => self.visit_bin_op(i),
             Syn::Binding(i) => self.visit_binding(i),
-            Syn::Block(_i) => { /* self.visit_block(i) */ }
+            Syn::Block(i) => self.visit_block(i),
             Syn::BoundLifetimes(_i) => { /* self.visit_bound_lifetimes(i) */ }
             Syn::ConstParam(i) => self.visit_const_param(i),
-            Syn::Constraint(_i) => { /* self.visit_constraint(i) */ }
+            Syn::Constraint(i) => self.visit_constraint(i),
             Syn::Expr(_i) => { /* self.visit_expr(i) */ }
             Syn::ExprArray(i) => self.visit_expr_array(i),
             Syn::ExprAssign(i) => self.visit_expr_assign(i),
@@ -393,7 +524,7 @@ impl<'a> NodeAnalyzer<'a> {
             Syn::ExprBlock(_i) => { /* self.visit_expr_block(i) */ }
             Syn::ExprBox(i) => self.visit_expr_box(i),
             Syn::ExprBreak(i) => self.visit_expr_break(i),
-            Syn::ExprCall(_i) => { /* self.visit_expr_call(i) */ }
+            Syn::ExprCall(i) => self.visit_expr_call(i),
             Syn::ExprCast(i) => self.visit_expr_cast(i),
             Syn::ExprClosure(i) => self.visit_expr_closure(i),
             Syn::ExprContinue(i) => self.visit_expr_continue(i),
@@ -407,7 +538,7 @@ impl<'a> NodeAnalyzer<'a> {
             Syn::ExprLoop(i) => self.visit_expr_loop(i),
             Syn::ExprMacro(_i) => { /* self.visit_expr_macro(i) */ }
             Syn::ExprMatch(i) => self.visit_expr_match(i),
-            Syn::ExprMethodCall(_i) => { /* self.visit_expr_method_call(i) */ }
+            Syn::ExprMethodCall(i) => self.visit_expr_method_call(i),
             Syn::ExprParen(_i) => { /* self.visit_expr_paren(i) */ }
             Syn::ExprPath(_i) => { /* self.visit_expr_path(i) */ }
             Syn::ExprRange(i) => self.visit_expr_range(i),
@@ -436,7 +567,7 @@ impl<'a> NodeAnalyzer<'a> {
             Syn::ForeignItemMacro(_i) => { /* self.visit_foreign_item_macro(i) */ }
             Syn::ForeignItemStatic(i) => self.visit_foreign_item_static(i),
             Syn::ForeignItemType(i) => self.visit_foreign_item_type(i),
-            Syn::GenericArgument(_i) => { /* self.visit_generic_argument(i) */ }
+            Syn::GenericArgument(i) => self.visit_generic_argument(i),
             Syn::GenericMethodArgument(_i) => { /* self.visit_generic_method_argument(i) */ }
             Syn::GenericParam(i) => self.visit_generic_param(i),
             Syn::Generics(_i) => { /* self.visit_generics(i) */ }
@@ -466,7 +597,7 @@ impl<'a> NodeAnalyzer<'a> {
             Syn::ItemUse(i) => self.visit_item_use(i),
             Syn::Label(i) => self.visit_label(i),
             Syn::Lifetime(i) => self.visit_lifetime(i),
-            Syn::LifetimeDef(_i) => { /* self.visit_lifetime_def(i) */ }
+            Syn::LifetimeDef(i) => self.visit_lifetime_def(i),
             Syn::Lit(_i) => { /* self.visit_lit(i) */ }
             Syn::LitBool(i) => self.visit_lit_bool(i),
             Syn::LitByte(i) => self.visit_lit_byte(i),
@@ -487,21 +618,21 @@ impl<'a> NodeAnalyzer<'a> {
             Syn::PatLit(_i) => { /* self.visit_pat_lit(i) */ }
             Syn::PatMacro(_i) => { /* self.visit_pat_macro(i) */ }
             Syn::PatOr(i) => self.visit_pat_or(i),
-            Syn::PatPath(_i) => { /* self.visit_pat_path(i) */ }
+            Syn::PatPath(i) => self.visit_pat_path(i),
             Syn::PatRange(i) => self.visit_pat_range(i),
-            Syn::PatReference(_i) => { /* self.visit_pat_reference(i) */ }
+            Syn::PatReference(i) => self.visit_pat_reference(i),
             Syn::PatRest(_i) => { /* self.visit_pat_rest(i) */ }
-            Syn::PatSlice(_i) => { /* self.visit_pat_slice(i) */ }
+            Syn::PatSlice(i) => self.visit_pat_slice(i),
             Syn::PatStruct(i) => self.visit_pat_struct(i),
             Syn::PatTuple(i) => self.visit_pat_tuple(i),
             Syn::PatTupleStruct(i) => self.visit_pat_tuple_struct(i),
-            Syn::PatType(_i) => { /* self.visit_pat_type(i) */ }
+            Syn::PatType(i) => self.visit_pat_type(i),
             Syn::PatWild(i) => self.visit_pat_wild(i),
             Syn::Path(i) => self.visit_path(i),
             Syn::PathArguments(_i) => { /* self.visit_path_arguments(i) */ }
             Syn::PathSegment(i) => self.visit_path_segment(i),
-            Syn::PredicateEq(_i) => { /* self.visit_predicate_eq(i) */ }
-            Syn::PredicateLifetime(_i) => { /* self.visit_predicate_lifetime(i) */ }
+            Syn::PredicateEq(i) => self.visit_predicate_eq(i),
+            Syn::PredicateLifetime(i) => self.visit_predicate_lifetime(i),
             Syn::PredicateType(i) => self.visit_predicate_type(i),
             Syn::QSelf(i) => self.visit_qself(i),
             Syn::Receiver(_i) => { /* self.visit_receiver(i) */ }
@@ -755,25 +886,26 @@ impl<'a> NodeAnalyzer<'a> {
 
     fn visit_bin_op(&mut self, node: &syn::BinOp) {
         use syn::BinOp::*;
+        let (grouping, chained) = self.operator_grouping();
         let item = match node {
-            Add(..) => HelpItem::AddBinOp,
-            Sub(..) => HelpItem::SubBinOp,
-            Mul(..) => HelpItem::MulBinOp,
-            Div(..) => HelpItem::DivBinOp,
-            Rem(..) => HelpItem::RemBinOp,
-            And(..) => HelpItem::AndBinOp,
-            Or(..) => HelpItem::OrBinOp,
-            BitXor(..) => HelpItem::BitXorBinOp,
-            BitAnd(..) => HelpItem::BitAndBinOp,
-            BitOr(..) => HelpItem::BitOrBinOp,
-            Shl(..) => HelpItem::ShlBinOp,
-            Shr(..) => HelpItem::ShrBinOp,
-            Eq(..) => HelpItem::EqBinOp,
-            Lt(..) => HelpItem::LtBinOp,
-            Le(..) => HelpItem::LeBinOp,
-            Ne(..) => HelpItem::NeBinOp,
-            Ge(..) => HelpItem::GeBinOp,
-            Gt(..) => HelpItem::GtBinOp,
+            Add(..) => HelpItem::AddBinOp { grouping },
+            Sub(..) => HelpItem::SubBinOp { grouping },
+            Mul(..) => HelpItem::MulBinOp { grouping },
+            Div(..) => HelpItem::DivBinOp { grouping },
+            Rem(..) => HelpItem::RemBinOp { grouping },
+            And(..) => HelpItem::AndBinOp { grouping },
+            Or(..) => HelpItem::OrBinOp { grouping },
+            BitXor(..) => HelpItem::BitXorBinOp { grouping },
+            BitAnd(..) => HelpItem::BitAndBinOp { grouping },
+            BitOr(..) => HelpItem::BitOrBinOp { grouping },
+            Shl(..) => HelpItem::ShlBinOp { grouping },
+            Shr(..) => HelpItem::ShrBinOp { grouping },
+            Eq(..) => HelpItem::EqBinOp { grouping, chained },
+            Lt(..) => HelpItem::LtBinOp { grouping, chained },
+            Le(..) => HelpItem::LeBinOp { grouping, chained },
+            Ne(..) => HelpItem::NeBinOp { grouping, chained },
+            Ge(..) => HelpItem::GeBinOp { grouping, chained },
+            Gt(..) => HelpItem::GtBinOp { grouping, chained },
             AddEq(..) => HelpItem::AddEqBinOp,
             SubEq(..) => HelpItem::SubEqBinOp,
             MulEq(..) => HelpItem::MulEqBinOp,
@@ -793,6 +925,18 @@ impl<'a> NodeAnalyzer<'a> {
             node,
             HelpItem::Binding {
                 ident: node.ident.to_string(),
+                trait_: self.constrained_trait(),
+                ty: node.ty.to_token_stream().to_string(),
+            },
+        );
+    }
+    fn visit_constraint(&mut self, node: &syn::Constraint) {
+        return self.set_help(
+            node,
+            HelpItem::Constraint {
+                ident: node.ident.to_string(),
+                trait_: self.constrained_trait(),
+                bounds: node.bounds.to_token_stream().to_string(),
             },
         );
     }
@@ -825,6 +969,16 @@ impl<'a> NodeAnalyzer<'a> {
         }
     }
     fn visit_field_pat(&mut self, node: &syn::FieldPat) {
+        if let (syn::Member::Named(ident), Some(Syn::PatStruct(pat_struct))) =
+            (&node.member, self.get_ancestor(1).map(|(_, node)| node))
+        {
+            if self.within(ident) {
+                if let Some(help) = self.field_privacy_violation(&pat_struct.path, ident) {
+                    return self.set_help(ident, help);
+                }
+            }
+        }
+
         if let (syn::Member::Unnamed(..), syn::Pat::Ident(syn::PatIdent { ident, .. })) =
             (&node.member, &*node.pat)
         {
@@ -857,6 +1011,16 @@ impl<'a> NodeAnalyzer<'a> {
         }
     }
     fn visit_field_value(&mut self, node: &syn::FieldValue) {
+        if let (syn::Member::Named(ident), Some(expr_struct)) =
+            (&node.member, get_ancestor![self, ExprStruct, 1])
+        {
+            if self.within(ident) {
+                if let Some(help) = self.field_privacy_violation(&expr_struct.path, ident) {
+                    return self.set_help(ident, help);
+                }
+            }
+        }
+
         match (node.colon_token, &node.member) {
             (None, syn::Member::Named(ident)) => {
                 return self.set_help(
@@ -878,6 +1042,11 @@ impl<'a> NodeAnalyzer<'a> {
         // TODO: assign proper span to shebang
         token![self, some node.shebang, Shebang];
     }
+    fn visit_fn_arg_first_pass(&mut self, node: &syn::FnArg) {
+        if let syn::FnArg::Typed(pat_type) = node {
+            self.visit_irrefutable_pat_first_pass(&pat_type.pat, BindingOf::Arg, None);
+        }
+    }
     fn visit_fn_arg(&mut self, node: &syn::FnArg) {
         if let Some(sig) = get_ancestor![self, Signature, 1] {
             if sig
@@ -904,9 +1073,9 @@ impl<'a> NodeAnalyzer<'a> {
                 node.static_token.span(),
                 end,
                 if node.mutability.is_some() {
-                    HelpItem::StaticMut
+                    HelpItem::StaticMut { visibility: None }
                 } else {
-                    HelpItem::Static
+                    HelpItem::Static { visibility: None }
                 },
             );
         }
@@ -914,6 +1083,12 @@ impl<'a> NodeAnalyzer<'a> {
     fn visit_foreign_item_type(&mut self, node: &syn::ForeignItemType) {
         token![self, node.type_token, ForeignItemType];
     }
+    fn visit_generic_argument(&mut self, node: &syn::GenericArgument) {
+        if let syn::GenericArgument::Const(expr) = node {
+            let (value, unevaluated) = self.evaluate_const(expr, None);
+            return self.set_help(node, HelpItem::GenericArgumentConst { value, unevaluated });
+        }
+    }
     fn visit_ident(&mut self, node: &proc_macro2::Ident) {
         let raw = node.to_string();
 
@@ -945,6 +1120,28 @@ impl<'a> NodeAnalyzer<'a> {
         return self.set_help(&node, HelpItem::Label { loop_of });
     }
     fn visit_lifetime(&mut self, node: &syn::Lifetime) {
+        if self.has_ancestor(1, SynKind::GenericArgument) {
+            if let Some(item) = self.elided_lifetime_argument_help(node) {
+                return self.set_help(node, item);
+            }
+        }
+
+        if let Some(ty) = self.lifetime_bounded_type() {
+            return self.set_help(
+                node,
+                HelpItem::TypeLifetimeBound {
+                    ty,
+                    lifetime: node.to_string(),
+                    static_: node.ident == "static",
+                },
+            );
+        }
+
+        // Explained as a whole in visit_predicate_lifetime
+        if self.has_ancestor(1, SynKind::PredicateLifetime) {
+            return;
+        }
+
         if node.ident == "static" {
             return self.set_help(node, HelpItem::StaticLifetime);
         }
@@ -1017,7 +1214,9 @@ impl<'a> NodeAnalyzer<'a> {
         return self.set_help(node, HelpItem::LitFloat { suffix, separators });
     }
     fn visit_lit_int(&mut self, node: &syn::LitInt) {
-        if self.has_ancestor(4, SynKind::TypeArray) {
+        if self.has_ancestor(4, SynKind::TypeArray)
+            || self.has_ancestor(4, SynKind::GenericArgument)
+        {
             return;
         }
 
@@ -1052,6 +1251,12 @@ impl<'a> NodeAnalyzer<'a> {
         return self.set_help(node, HelpItem::LitStr { raw, prefix });
     }
     fn visit_local_first_pass(&mut self, node: &syn::Local) {
+        let init = node.init.as_ref().map(|(_, expr)| &**expr);
+        self.visit_irrefutable_pat_first_pass(&node.pat, BindingOf::Let, init);
+        if self.help.is_some() {
+            return;
+        }
+
         let ident_pat = match &node.pat {
             syn::Pat::Ident(pat) => Some(pat),
             syn::Pat::Type(syn::PatType { pat, .. }) => match &**pat {
@@ -1094,7 +1299,11 @@ impl<'a> NodeAnalyzer<'a> {
             return self.set_help_between(
                 node.path.span(),
                 node.bang_token.span(),
-                HelpItem::Macro,
+                if self.has_ancestor(1, SynKind::PatMacro) {
+                    HelpItem::PatMacro
+                } else {
+                    HelpItem::Macro
+                },
             );
         }
         token![self, node.tokens, MacroTokens];
@@ -1128,6 +1337,12 @@ impl<'a> NodeAnalyzer<'a> {
             .map(|q| q.as_token.is_some())
             .unwrap_or(false);
 
+        if let Some((ident, help)) = self.path_privacy_violation(node) {
+            if self.within(ident) {
+                return self.set_help(ident, help);
+            }
+        }
+
         if special_path_help(
             self,
             node.leading_colon.filter(|_| simple_qself),
@@ -1198,7 +1413,16 @@ impl<'a> NodeAnalyzer<'a> {
         token![self, some node.asyncness, AsyncFn];
         token![self, some node.constness, ConstFn];
         token![self, some node.abi, FnAbi];
-        token![self, some node.unsafety, UnsafeFn];
+        token![
+            self,
+            some node.unsafety,
+            *HelpItem::UnsafeFn {
+                operations: match self.get_ancestor(1) {
+                    Some((_, parent)) => self.unsafe_operation_labels(parent),
+                    None => vec![],
+                },
+            }
+        ];
     }
     fn visit_trait_bound_first_pass(&mut self, node: &syn::TraitBound) {
         let lifetimes = node
@@ -1293,18 +1517,39 @@ impl<'a> NodeAnalyzer<'a> {
         token![self, node.as_token => node.rename, * HelpItem::AsRename { underscore: node.rename == "_"}];
     }
     fn visit_variant(&mut self, node: &syn::Variant) {
+        let item_enum = get_ancestor![self, ItemEnum, 1];
+        let discriminant = item_enum
+            .and_then(|item_enum| {
+                let idx = item_enum
+                    .variants
+                    .iter()
+                    .position(|variant| variant.ident == node.ident)?;
+                self.discriminants(item_enum)?.get(idx).copied()
+            })
+            .map(|discriminant| match discriminant {
+                Ok(value) => (Some(value.to_string()), None),
+                Err(error) => (None, Some(error)),
+            });
+        let (value, unevaluated) = discriminant.unwrap_or((None, None));
+
         if let Some((eq_token, discriminant)) = &node.discriminant {
             if self.between(&eq_token, &discriminant) {
+                let value = match discriminant {
+                    syn::Expr::Lit(..) => None,
+                    _ => value,
+                };
                 return self.set_help_between(
                     eq_token.span(),
                     discriminant.span(),
                     HelpItem::VariantDiscriminant {
                         name: node.ident.to_string(),
+                        value,
+                        unevaluated,
                     },
                 );
             }
         }
-        let name = if let Some(item_enum) = get_ancestor![self, ItemEnum, 1] {
+        let name = if let Some(item_enum) = item_enum {
             item_enum.ident.to_string()
         } else {
             return;
@@ -1319,15 +1564,28 @@ impl<'a> NodeAnalyzer<'a> {
                     syn::Fields::Unnamed(..) => Some(Fields::Unnamed),
                     syn::Fields::Unit => None,
                 },
+                discriminant: value,
+                unevaluated,
             },
         );
     }
     fn visit_vis_crate(&mut self, node: &syn::VisCrate) {
-        return self.set_help(node, HelpItem::VisCrate);
+        return self.set_help(
+            node,
+            HelpItem::VisCrate {
+                visibility: self.owner_visibility(),
+            },
+        );
     }
     fn visit_vis_public(&mut self, node: &syn::VisPublic) {
         let field = self.has_ancestor(2, SynKind::Field);
-        return self.set_help(node, HelpItem::VisPublic { field });
+        return self.set_help(
+            node,
+            HelpItem::VisPublic {
+                field,
+                visibility: self.owner_visibility(),
+            },
+        );
     }
     fn visit_vis_restricted_first_pass(&mut self, node: &syn::VisRestricted) {
         let path = match &*node.path {
@@ -1341,6 +1599,7 @@ impl<'a> NodeAnalyzer<'a> {
             HelpItem::VisRestricted {
                 path,
                 in_: node.in_token.is_some(),
+                visibility: self.owner_visibility(),
             },
         );
     }
@@ -1513,6 +1772,15 @@ fn receiver_help(sig: &syn::Signature) -> Option<HelpItem> {
     }
 }
 
+fn receiver_kind(sig: &syn::Signature) -> Option<SelfKind> {
+    receiver_help(sig).map(|item| match item {
+        HelpItem::RefSelf { .. } => SelfKind::Ref,
+        HelpItem::MutSelf { .. } => SelfKind::RefMut,
+        HelpItem::ValueSelf { .. } => SelfKind::Value,
+        _ => SelfKind::Special,
+    })
+}
+
 pub fn within_locations(loc: LineColumn, start: LineColumn, end: LineColumn) -> bool {
     (start.line < loc.line || (start.line == loc.line && start.column <= loc.column))
         && (loc.line < end.line || (loc.line == end.line && loc.column <= end.column))
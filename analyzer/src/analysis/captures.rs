@@ -0,0 +1,413 @@
+use super::{
+    receiver_kind,
+    resolution::{bound_idents, LocalBinding},
+    NodeAnalyzer,
+};
+use crate::help::{ClosureTrait, ClosureTraitBound, HelpItem, SelfKind};
+use proc_macro2::{TokenStream, TokenTree};
+
+/// How a captured variable is used in the body of a closure, from weakest to strongest
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Usage {
+    Ref,
+    Mut,
+    Move,
+}
+
+impl<'a> NodeAnalyzer<'a> {
+    pub(super) fn closure_help(&self, node: &syn::ExprClosure, move_token: bool) -> HelpItem {
+        let mut collector = CaptureCollector {
+            analyzer: self,
+            bound: vec![],
+            captures: vec![],
+        };
+        for input in &node.inputs {
+            collector.bind(input);
+        }
+        collector.expr(&node.body, Usage::Move);
+
+        let captures = collector.captures;
+        let fn_trait = ClosureTraitBound {
+            trait_: match captures.iter().map(|capture| capture.usage).max() {
+                Some(Usage::Move) => ClosureTrait::FnOnce,
+                Some(Usage::Mut) => ClosureTrait::FnMut,
+                _ => ClosureTrait::Fn,
+            },
+            uncertain: captures
+                .iter()
+                .any(|capture| capture.unknown && capture.usage < Usage::Move),
+        };
+
+        let names = |filter: &dyn Fn(&Capture) -> bool| -> Vec<String> {
+            captures
+                .iter()
+                .filter(|capture| filter(capture))
+                .map(|capture| capture.name.clone())
+                .collect()
+        };
+
+        // A `move` closure takes ownership of everything it captures
+        let moved = node.capture.is_some();
+        let by_ref = names(&|capture| !moved && capture.usage == Usage::Ref && !capture.unknown);
+        let by_unknown = names(&|capture| !moved && capture.usage == Usage::Ref && capture.unknown);
+        let by_mut = names(&|capture| !moved && capture.usage == Usage::Mut);
+        let by_move = names(&|capture| moved || capture.usage == Usage::Move);
+
+        if move_token {
+            HelpItem::ExprClosureMove { by_move, fn_trait }
+        } else {
+            HelpItem::ExprClosureArguments {
+                by_ref,
+                by_unknown,
+                by_mut,
+                by_move,
+                fn_trait,
+            }
+        }
+    }
+}
+
+/// Finds the variables from the enclosing scope that are used in a closure
+struct CaptureCollector<'c, 'a> {
+    analyzer: &'c NodeAnalyzer<'a>,
+    /// Names bound inside the closure that are in scope, which shadow the outer ones
+    bound: Vec<String>,
+    /// Captured variables in order of appearance
+    captures: Vec<Capture>,
+}
+
+struct Capture {
+    name: String,
+    /// The strongest usage
+    usage: Usage,
+    /// Whether it is the receiver of a method that isn't declared in the file, or a field of
+    /// unknown type is moved out of it
+    unknown: bool,
+}
+
+impl<'c, 'a> CaptureCollector<'c, 'a> {
+    /// Brings the bindings of a pattern into scope, until the scope is left with `unbind`
+    fn bind(&mut self, pat: &syn::Pat) {
+        let idents = bound_idents(pat);
+        self.bound
+            .extend(idents.iter().map(|pat_ident| pat_ident.ident.to_string()));
+    }
+
+    fn unbind(&mut self, len: usize) {
+        self.bound.truncate(len);
+    }
+
+    fn capture(&mut self, ident: &syn::Ident, usage: Usage) {
+        let name = ident.to_string();
+        if self.bound.contains(&name) {
+            return;
+        }
+
+        let binding = match self.analyzer.find_local(ident) {
+            Some(binding) => binding,
+            None => return,
+        };
+
+        // Using a `Copy` value copies it, which only needs to read it
+        let usage = if usage == Usage::Move && is_copy(&binding) {
+            Usage::Ref
+        } else {
+            usage
+        };
+
+        match self
+            .captures
+            .iter_mut()
+            .find(|capture| capture.name == name)
+        {
+            Some(capture) => capture.usage = std::cmp::max(capture.usage, usage),
+            None => self.captures.push(Capture {
+                name,
+                usage,
+                unknown: false,
+            }),
+        }
+    }
+
+    /// Marks the variable a method receiver refers to as used by a method that isn't declared in
+    /// the file
+    fn unknown_receiver(&mut self, receiver: &syn::Expr) {
+        use syn::Expr::*;
+
+        let ident = match receiver {
+            Path(expr_path) if expr_path.qself.is_none() => match expr_path.path.get_ident() {
+                Some(ident) => ident.to_string(),
+                None => return,
+            },
+            Field(syn::ExprField { base: expr, .. })
+            | Index(syn::ExprIndex { expr, .. })
+            | Paren(syn::ExprParen { expr, .. })
+            | Group(syn::ExprGroup { expr, .. }) => return self.unknown_receiver(expr),
+            _ => return,
+        };
+        if self.bound.contains(&ident) {
+            return;
+        }
+        if let Some(capture) = self
+            .captures
+            .iter_mut()
+            .find(|capture| capture.name == ident)
+        {
+            capture.unknown = true;
+        }
+    }
+
+    /// Visits an expression whose value is used as described by `usage`
+    fn expr(&mut self, expr: &syn::Expr, usage: Usage) {
+        use syn::Expr::*;
+
+        match expr {
+            Path(expr_path) => {
+                if let (None, Some(ident)) = (&expr_path.qself, expr_path.path.get_ident()) {
+                    self.capture(ident, usage);
+                }
+            }
+            Array(array) => array.elems.iter().for_each(|elem| self.expr(elem, usage)),
+            Assign(assign) => {
+                self.expr(&assign.left, Usage::Mut);
+                self.expr(&assign.right, Usage::Move);
+            }
+            AssignOp(assign_op) => {
+                self.expr(&assign_op.left, Usage::Mut);
+                self.expr(&assign_op.right, Usage::Ref);
+            }
+            Async(expr_async) => self.block(&expr_async.block, Usage::Move),
+            Await(expr_await) => self.expr(&expr_await.base, Usage::Move),
+            Binary(binary) => {
+                self.expr(&binary.left, Usage::Ref);
+                self.expr(&binary.right, Usage::Ref);
+            }
+            Block(expr_block) => self.block(&expr_block.block, usage),
+            Box(expr_box) => self.expr(&expr_box.expr, Usage::Move),
+            Break(expr_break) => self.some_expr(&expr_break.expr, Usage::Move),
+            Call(call) => {
+                self.expr(&call.func, Usage::Ref);
+                call.args.iter().for_each(|arg| self.expr(arg, Usage::Move));
+            }
+            Cast(cast) => self.expr(&cast.expr, Usage::Ref),
+            Closure(closure) => {
+                let usage = if closure.capture.is_some() {
+                    Usage::Move
+                } else {
+                    Usage::Ref
+                };
+                let len = self.bound.len();
+                closure.inputs.iter().for_each(|input| self.bind(input));
+                self.expr(&closure.body, usage);
+                self.unbind(len);
+            }
+            // Moving a field out only copies it if the field is `Copy`
+            Field(field) if usage == Usage::Move => match self.field_type(field) {
+                Some(ty) if is_copy_type(ty) => self.expr(&field.base, Usage::Ref),
+                Some(_) => self.expr(&field.base, Usage::Move),
+                None => {
+                    self.expr(&field.base, Usage::Ref);
+                    self.unknown_receiver(&field.base);
+                }
+            },
+            Field(field) => self.expr(&field.base, usage),
+            ForLoop(for_loop) => {
+                self.expr(&for_loop.expr, Usage::Move);
+                let len = self.bound.len();
+                self.bind(&for_loop.pat);
+                self.block(&for_loop.body, Usage::Ref);
+                self.unbind(len);
+            }
+            Group(group) => self.expr(&group.expr, usage),
+            If(expr_if) => {
+                self.expr(&expr_if.cond, Usage::Ref);
+                let len = self.bound.len();
+                self.bind_let(&expr_if.cond);
+                self.block(&expr_if.then_branch, usage);
+                self.unbind(len);
+                if let Some((_, else_branch)) = &expr_if.else_branch {
+                    self.expr(else_branch, usage);
+                }
+            }
+            Index(index) => {
+                self.expr(&index.expr, std::cmp::min(usage, Usage::Mut));
+                self.expr(&index.index, Usage::Ref);
+            }
+            Let(expr_let) => self.expr(&expr_let.expr, Usage::Ref),
+            Loop(expr_loop) => self.block(&expr_loop.body, usage),
+            Macro(expr_macro) => self.tokens(expr_macro.mac.tokens.clone()),
+            Match(expr_match) => {
+                self.expr(&expr_match.expr, Usage::Ref);
+                for arm in &expr_match.arms {
+                    let len = self.bound.len();
+                    self.bind(&arm.pat);
+                    if let Some((_, guard)) = &arm.guard {
+                        self.expr(guard, Usage::Ref);
+                    }
+                    self.expr(&arm.body, usage);
+                    self.unbind(len);
+                }
+            }
+            MethodCall(method_call) => {
+                let receiver = self
+                    .analyzer
+                    .find_methods(&method_call.method)
+                    .iter()
+                    .find_map(|method| receiver_kind(method.sig))
+                    .map(|kind| match kind {
+                        SelfKind::RefMut => Usage::Mut,
+                        SelfKind::Value => Usage::Move,
+                        _ => Usage::Ref,
+                    });
+                match receiver {
+                    Some(usage) => self.expr(&method_call.receiver, usage),
+                    None => {
+                        self.expr(&method_call.receiver, Usage::Ref);
+                        self.unknown_receiver(&method_call.receiver);
+                    }
+                }
+                method_call
+                    .args
+                    .iter()
+                    .for_each(|arg| self.expr(arg, Usage::Move));
+            }
+            Paren(paren) => self.expr(&paren.expr, usage),
+            Range(range) => {
+                self.some_expr(&range.from, usage);
+                self.some_expr(&range.to, usage);
+            }
+            Reference(reference) => self.expr(
+                &reference.expr,
+                if reference.mutability.is_some() {
+                    Usage::Mut
+                } else {
+                    Usage::Ref
+                },
+            ),
+            Repeat(repeat) => self.expr(&repeat.expr, Usage::Move),
+            Return(expr_return) => self.some_expr(&expr_return.expr, Usage::Move),
+            Struct(expr_struct) => {
+                for field in &expr_struct.fields {
+                    self.expr(&field.expr, Usage::Move);
+                }
+                self.some_expr(&expr_struct.rest, Usage::Move);
+            }
+            Try(expr_try) => self.expr(&expr_try.expr, Usage::Move),
+            TryBlock(try_block) => self.block(&try_block.block, usage),
+            Tuple(tuple) => tuple.elems.iter().for_each(|elem| self.expr(elem, usage)),
+            Type(expr_type) => self.expr(&expr_type.expr, usage),
+            Unary(unary) => match unary.op {
+                syn::UnOp::Deref(..) => self.expr(&unary.expr, std::cmp::min(usage, Usage::Mut)),
+                _ => self.expr(&unary.expr, Usage::Ref),
+            },
+            Unsafe(expr_unsafe) => self.block(&expr_unsafe.block, usage),
+            While(expr_while) => {
+                self.expr(&expr_while.cond, Usage::Ref);
+                let len = self.bound.len();
+                self.bind_let(&expr_while.cond);
+                self.block(&expr_while.body, Usage::Ref);
+                self.unbind(len);
+            }
+            Yield(expr_yield) => self.some_expr(&expr_yield.expr, Usage::Move),
+            _ => {}
+        }
+    }
+
+    /// The declared type of a field, if its base is a struct declared in the file
+    fn field_type(&self, field: &syn::ExprField) -> Option<&'a syn::Type> {
+        self.analyzer
+            .receiver_types(&field.base)
+            .iter()
+            .filter_map(|segment| self.analyzer.find_struct(&segment.ident.to_string()))
+            .find_map(|item| {
+                item.fields
+                    .iter()
+                    .enumerate()
+                    .find(
+                        |(idx, item_field)| match (&field.member, &item_field.ident) {
+                            (syn::Member::Named(name), Some(ident)) => name == ident,
+                            (syn::Member::Unnamed(index), None) => index.index as usize == *idx,
+                            _ => false,
+                        },
+                    )
+                    .map(|(_, item_field)| &item_field.ty)
+            })
+    }
+
+    fn some_expr(&mut self, expr: &Option<std::boxed::Box<syn::Expr>>, usage: Usage) {
+        if let Some(expr) = expr {
+            self.expr(expr, usage);
+        }
+    }
+
+    /// Brings the bindings of an `if let` or `while let` condition into scope
+    fn bind_let(&mut self, cond: &syn::Expr) {
+        if let syn::Expr::Let(expr_let) = cond {
+            self.bind(&expr_let.pat);
+        }
+    }
+
+    /// Visits a block whose value is used as described by `usage`
+    fn block(&mut self, block: &syn::Block, usage: Usage) {
+        let len = self.bound.len();
+        for (idx, stmt) in block.stmts.iter().enumerate() {
+            match stmt {
+                // The bindings of a `let` are only in scope after it
+                syn::Stmt::Local(local) => {
+                    if let Some((_, init)) = &local.init {
+                        self.expr(init, Usage::Move);
+                    }
+                    self.bind(&local.pat);
+                }
+                syn::Stmt::Expr(expr) if idx + 1 == block.stmts.len() => self.expr(expr, usage),
+                syn::Stmt::Expr(expr) | syn::Stmt::Semi(expr, _) => self.expr(expr, Usage::Ref),
+                syn::Stmt::Item(..) => {}
+            }
+        }
+        self.unbind(len);
+    }
+
+    /// Macro arguments can't be parsed in general, but most macros (like `println!`) only take
+    /// references to the variables they mention
+    fn tokens(&mut self, tokens: TokenStream) {
+        for tree in tokens {
+            match tree {
+                TokenTree::Ident(ident) => self.capture(&ident, Usage::Ref),
+                TokenTree::Group(group) => self.tokens(group.stream()),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Whether the binding is known to be `Copy`, from its type annotation or its initializer
+fn is_copy(binding: &LocalBinding) -> bool {
+    if let Some(ty) = binding.ty {
+        return is_copy_type(ty);
+    }
+
+    match binding.init {
+        // String literals are `&'static str`, which is also `Copy`
+        Some(syn::Expr::Lit(..)) => true,
+        Some(syn::Expr::Reference(reference)) => reference.mutability.is_none(),
+        _ => false,
+    }
+}
+
+/// Whether the type is a primitive or a shared reference
+fn is_copy_type(ty: &syn::Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16",
+        "u32", "u64", "u128", "usize",
+    ];
+
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .get_ident()
+            .map(|ident| PRIMITIVES.contains(&ident.to_string().as_str()))
+            .unwrap_or(false),
+        syn::Type::Reference(reference) => reference.mutability.is_none(),
+        _ => false,
+    }
+}
@@ -0,0 +1,285 @@
+use super::{printing::tokens_to_string, NodeAnalyzer};
+use crate::help::{CastKind, HelpItem};
+
+/// Primitive types that take part in numeric casts
+#[derive(Clone, Copy, PartialEq)]
+pub(super) enum Primitive {
+    /// Pointer sized integers have no `bits`
+    Int {
+        signed: bool,
+        bits: Option<u32>,
+    },
+    Float {
+        bits: u32,
+    },
+    Bool,
+    Char,
+    Pointer,
+}
+
+/// The value of a literal operand, as it goes through a chain of casts
+#[derive(Clone, Copy)]
+enum Value {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+}
+
+/// Possible widths of `usize` and `isize`
+const POINTER_WIDTHS: (u32, u32) = (16, 64);
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Explains what happens to the value of a cast between primitive types, when the type of
+    /// the operand can be known syntactically
+    pub(super) fn cast_help(&self, node: &syn::ExprCast) -> HelpItem {
+        let to = tokens_to_string(&node.ty);
+        let unknown = HelpItem::AsCast {
+            from: None,
+            to: to.clone(),
+            conversion: CastKind::Unknown,
+            result: None,
+        };
+
+        let (from_name, from, operand) = match self.operand_type(&node.expr) {
+            Some(operand) => operand,
+            None => return unknown,
+        };
+        let target = match primitive(&node.ty) {
+            Some(target) => target,
+            None => return unknown,
+        };
+
+        let conversion = conversion(from, target);
+        let result = match conversion {
+            CastKind::Invalid | CastKind::Unknown => None,
+            _ => operand
+                .and_then(|value| cast_value(value, target))
+                .map(|value| format_value(value, target)),
+        };
+
+        HelpItem::AsCast {
+            from: Some(from_name),
+            to,
+            conversion,
+            result,
+        }
+    }
+
+    /// The type of a cast operand and its value, for literals. Only suffixed literals, annotated
+    /// locals and other casts have a type that doesn't depend on inference
+    fn operand_type(&self, expr: &syn::Expr) -> Option<(String, Primitive, Option<Value>)> {
+        let (name, value) = match expr {
+            syn::Expr::Paren(syn::ExprParen { expr, .. })
+            | syn::Expr::Group(syn::ExprGroup { expr, .. }) => return self.operand_type(expr),
+            syn::Expr::Lit(expr_lit) => literal(&expr_lit.lit)?,
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(..),
+                expr,
+                ..
+            }) => match &**expr {
+                syn::Expr::Lit(expr_lit) => {
+                    let (name, value) = literal(&expr_lit.lit)?;
+                    let value = match value {
+                        Some(Value::Int(value)) => Some(Value::Int(-value)),
+                        Some(Value::Float(value)) => Some(Value::Float(-value)),
+                        _ => None,
+                    };
+                    (name, value)
+                }
+                _ => return None,
+            },
+            syn::Expr::Cast(expr_cast) => {
+                let target = primitive(&expr_cast.ty)?;
+                let value = self
+                    .operand_type(&expr_cast.expr)
+                    .and_then(|(_, from, value)| match conversion(from, target) {
+                        CastKind::Invalid | CastKind::Unknown => None,
+                        _ => cast_value(value?, target),
+                    });
+                return Some((tokens_to_string(&expr_cast.ty), target, value));
+            }
+            syn::Expr::Path(expr_path) => {
+                let ty = self.find_local(expr_path.path.get_ident()?)?.ty?;
+                return Some((tokens_to_string(ty), primitive(ty)?, None));
+            }
+            _ => return None,
+        };
+
+        Some((name.clone(), primitive_named(&name)?, value))
+    }
+}
+
+/// The type of a suffixed literal, or of a `bool`, `char` or byte literal
+fn literal(lit: &syn::Lit) -> Option<(String, Option<Value>)> {
+    match lit {
+        syn::Lit::Int(lit_int) if !lit_int.suffix().is_empty() => Some((
+            lit_int.suffix().to_string(),
+            lit_int.base10_parse().ok().map(Value::Int),
+        )),
+        syn::Lit::Float(lit_float) if lit_float.suffix() == "f32" => Some((
+            "f32".to_string(),
+            lit_float
+                .base10_parse::<f32>()
+                .ok()
+                .map(|value| Value::Float(value as f64)),
+        )),
+        syn::Lit::Float(lit_float) if !lit_float.suffix().is_empty() => Some((
+            lit_float.suffix().to_string(),
+            lit_float.base10_parse().ok().map(Value::Float),
+        )),
+        syn::Lit::Bool(lit_bool) => Some(("bool".to_string(), Some(Value::Bool(lit_bool.value)))),
+        syn::Lit::Char(lit_char) => Some(("char".to_string(), Some(Value::Char(lit_char.value())))),
+        syn::Lit::Byte(lit_byte) => {
+            Some(("u8".to_string(), Some(Value::Int(lit_byte.value() as i128))))
+        }
+        _ => None,
+    }
+}
+
+pub(super) fn primitive(ty: &syn::Type) -> Option<Primitive> {
+    match ty {
+        syn::Type::Paren(syn::TypeParen { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. }) => primitive(elem),
+        syn::Type::Ptr(..) => Some(Primitive::Pointer),
+        syn::Type::Path(type_path) if type_path.qself.is_none() => {
+            primitive_named(&type_path.path.get_ident()?.to_string())
+        }
+        _ => None,
+    }
+}
+
+pub(super) fn primitive_named(name: &str) -> Option<Primitive> {
+    let int = |signed, bits| Some(Primitive::Int { signed, bits });
+    match name {
+        "i8" => int(true, Some(8)),
+        "i16" => int(true, Some(16)),
+        "i32" => int(true, Some(32)),
+        "i64" => int(true, Some(64)),
+        "i128" => int(true, Some(128)),
+        "isize" => int(true, None),
+        "u8" => int(false, Some(8)),
+        "u16" => int(false, Some(16)),
+        "u32" => int(false, Some(32)),
+        "u64" => int(false, Some(64)),
+        "u128" => int(false, Some(128)),
+        "usize" => int(false, None),
+        "f32" => Some(Primitive::Float { bits: 32 }),
+        "f64" => Some(Primitive::Float { bits: 64 }),
+        "bool" => Some(Primitive::Bool),
+        "char" => Some(Primitive::Char),
+        _ => None,
+    }
+}
+
+fn conversion(from: Primitive, to: Primitive) -> CastKind {
+    use Primitive::*;
+
+    match (from, to) {
+        // The pointee types aren't known, so they may or may not differ
+        (Pointer, Pointer) => CastKind::PointerToPointer,
+        (from, to) if from == to => CastKind::Identity,
+        (Int { signed, bits: from }, Int { bits: to, .. }) => {
+            let widths =
+                |bits: Option<u32>| bits.map(|bits| (bits, bits)).unwrap_or(POINTER_WIDTHS);
+            let ((from_min, from_max), (to_min, to_max)) = (widths(from), widths(to));
+            if from == to {
+                CastKind::Reinterpret
+            } else if to_max < from_min {
+                CastKind::Truncate
+            } else if to_min > from_max && signed {
+                CastKind::SignExtend
+            } else if to_min > from_max {
+                CastKind::ZeroExtend
+            } else {
+                CastKind::PointerSized
+            }
+        }
+        (Float { .. }, Int { .. }) => CastKind::FloatToInt,
+        (Int { .. }, Float { .. }) => CastKind::IntToFloat,
+        (Float { bits: 32 }, Float { .. }) => CastKind::FloatWiden,
+        (Float { .. }, Float { .. }) => CastKind::FloatNarrow,
+        (
+            Int {
+                signed: false,
+                bits: Some(8),
+            },
+            Char,
+        ) => CastKind::ByteToChar,
+        (Char, Int { .. }) => CastKind::CharToInt,
+        (Bool, Int { .. }) => CastKind::BoolToInt,
+        (Pointer, Int { .. }) => CastKind::PointerToInt,
+        (_, Bool) | (_, Char) | (Bool, Float { .. }) | (Char, Float { .. }) => CastKind::Invalid,
+        _ => CastKind::Unknown,
+    }
+}
+
+/// Computes the result of casting `value`. Results that depend on the pointer width of the
+/// target are not computed
+fn cast_value(value: Value, to: Primitive) -> Option<Value> {
+    let value = match (value, to) {
+        (Value::Int(value), Primitive::Int { signed, bits }) => {
+            Value::Int(wrap(value, signed, bits?)?)
+        }
+        (Value::Char(value), Primitive::Int { signed, bits }) => {
+            Value::Int(wrap(value as i128, signed, bits?)?)
+        }
+        (Value::Bool(value), Primitive::Int { .. }) => Value::Int(value as i128),
+        (Value::Float(value), Primitive::Int { signed, bits }) => {
+            let bits = bits?;
+            if bits == 128 && !signed {
+                return None;
+            }
+            let (min, max) = range(signed, bits);
+            // Float to int casts saturate, and NaN becomes 0
+            Value::Int((value as i128).clamp(min, max))
+        }
+        (Value::Int(value), Primitive::Float { bits: 32 }) => Value::Float(value as f32 as f64),
+        (Value::Int(value), Primitive::Float { .. }) => Value::Float(value as f64),
+        (Value::Float(value), Primitive::Float { bits: 32 }) => Value::Float(value as f32 as f64),
+        (Value::Float(value), Primitive::Float { .. }) => Value::Float(value),
+        (Value::Int(value), Primitive::Char) => Value::Char(value as u8 as char),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Keeps the lowest `bits` of the two's complement representation of `value`
+pub(super) fn wrap(value: i128, signed: bool, bits: u32) -> Option<i128> {
+    if bits == 128 {
+        return if signed || value >= 0 {
+            Some(value)
+        } else {
+            None
+        };
+    }
+
+    let truncated = (value as u128 & ((1 << bits) - 1)) as i128;
+    if signed && truncated >= 1 << (bits - 1) {
+        Some(truncated - (1 << bits))
+    } else {
+        Some(truncated)
+    }
+}
+
+/// The smallest and largest values of an integer type. Values of `u128` above `i128::MAX` are
+/// not supported
+pub(super) fn range(signed: bool, bits: u32) -> (i128, i128) {
+    match (signed, bits) {
+        (true, 128) => (i128::MIN, i128::MAX),
+        (false, 128) => (0, i128::MAX),
+        (true, bits) => (-(1 << (bits - 1)), (1 << (bits - 1)) - 1),
+        (false, bits) => (0, (1 << bits) - 1),
+    }
+}
+
+fn format_value(value: Value, ty: Primitive) -> String {
+    match (value, ty) {
+        (Value::Float(value), Primitive::Float { bits: 32 }) => format!("{:?}", value as f32),
+        (Value::Float(value), _) => format!("{:?}", value),
+        (Value::Int(value), _) => value.to_string(),
+        (Value::Bool(value), _) => value.to_string(),
+        (Value::Char(value), _) => format!("{:?}", value),
+    }
+}
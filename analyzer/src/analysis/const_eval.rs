@@ -0,0 +1,306 @@
+use super::{
+    casts::{primitive, primitive_named, range, wrap, Primitive},
+    NodeAnalyzer,
+};
+use crate::{help::ConstEvalError, syn_wrappers::Syn};
+
+/// Integer types, as signedness and width
+type IntType = (bool, u32);
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Evaluates an integer constant expression of type `ty`, like an array length. Returns the
+    /// value, unless `expr` is just a literal, or the reason why it can't be evaluated
+    pub(super) fn evaluate_const(
+        &self,
+        expr: &syn::Expr,
+        ty: Option<Primitive>,
+    ) -> (Option<String>, Option<ConstEvalError>) {
+        if let syn::Expr::Lit(..) = expr {
+            return (None, None);
+        }
+
+        let mut evaluator = Evaluator {
+            analyzer: self,
+            evaluating: vec![],
+        };
+        match evaluator.evaluate(expr, ty.and_then(int_type)) {
+            Ok(value) => (Some(value.to_string()), None),
+            Err(error) => (None, Some(error)),
+        }
+    }
+
+    /// The discriminant of every variant of a fieldless enum: variants without an explicit
+    /// discriminant take the one of the previous variant plus one, starting at zero
+    pub(super) fn discriminants(
+        &self,
+        node: &syn::ItemEnum,
+    ) -> Option<Vec<Result<i128, ConstEvalError>>> {
+        let fieldless = node
+            .variants
+            .iter()
+            .all(|variant| std::matches!(variant.fields, syn::Fields::Unit));
+        if !fieldless {
+            return None;
+        }
+
+        // Without a `repr` attribute, discriminants are `isize`
+        let ty = repr(node).unwrap_or((true, 64));
+        let mut previous: Option<Result<i128, ConstEvalError>> = None;
+
+        Some(
+            node.variants
+                .iter()
+                .map(|variant| {
+                    let discriminant = match (&variant.discriminant, previous) {
+                        (Some((_, expr)), _) => Evaluator {
+                            analyzer: self,
+                            evaluating: vec![],
+                        }
+                        .evaluate(expr, Some(ty)),
+                        (None, None) => Ok(0),
+                        (None, Some(Ok(previous))) => previous
+                            .checked_add(1)
+                            .ok_or(if ty == (false, 128) {
+                                ConstEvalError::Unsupported
+                            } else {
+                                ConstEvalError::Overflow
+                            })
+                            .and_then(|value| check(value, Some(ty))),
+                        (None, Some(Err(error))) => Err(error),
+                    };
+                    previous = Some(discriminant);
+                    discriminant
+                })
+                .collect(),
+        )
+    }
+
+    /// Whether `ident` is a const generic parameter of one of the enclosing items
+    fn is_const_param(&self, ident: &syn::Ident) -> bool {
+        self.ancestors
+            .iter()
+            .filter_map(|(_, node)| generics(*node))
+            .flat_map(|generics| generics.const_params())
+            .any(|param| param.ident == *ident)
+    }
+}
+
+struct Evaluator<'n, 'a> {
+    analyzer: &'n NodeAnalyzer<'a>,
+    /// Constants being evaluated, to detect cycles
+    evaluating: Vec<String>,
+}
+
+impl<'n, 'a> Evaluator<'n, 'a> {
+    /// Evaluates `expr`, checking for overflows if its type is known
+    fn evaluate(&mut self, expr: &syn::Expr, ty: Option<IntType>) -> Result<i128, ConstEvalError> {
+        let value = self.evaluate_unchecked(expr, ty)?;
+        check(value, ty)
+    }
+
+    fn evaluate_unchecked(
+        &mut self,
+        expr: &syn::Expr,
+        ty: Option<IntType>,
+    ) -> Result<i128, ConstEvalError> {
+        match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit_int),
+                ..
+            }) => {
+                let value = lit_int.base10_parse().map_err(|_| too_large(lit_int))?;
+                check(value, primitive_named(lit_int.suffix()).and_then(int_type))
+            }
+            syn::Expr::Paren(syn::ExprParen { expr, .. })
+            | syn::Expr::Group(syn::ExprGroup { expr, .. }) => self.evaluate(expr, ty),
+            syn::Expr::Block(expr_block) => match &expr_block.block.stmts[..] {
+                [syn::Stmt::Expr(expr)] => self.evaluate(expr, ty),
+                _ => Err(ConstEvalError::Unsupported),
+            },
+            syn::Expr::Unary(expr_unary) => {
+                let value = self.evaluate(&expr_unary.expr, ty)?;
+                match (expr_unary.op, ty) {
+                    (syn::UnOp::Neg(..), _) => value.checked_neg().ok_or(ConstEvalError::Overflow),
+                    (syn::UnOp::Not(..), Some((true, _))) => Ok(!value),
+                    // Above `i128::MAX`
+                    (syn::UnOp::Not(..), Some((false, 128))) => Err(ConstEvalError::Unsupported),
+                    (syn::UnOp::Not(..), Some((false, bits))) => Ok(range(false, bits).1 ^ value),
+                    _ => Err(ConstEvalError::Unsupported),
+                }
+            }
+            syn::Expr::Binary(expr_binary) => self.evaluate_binary(expr_binary, ty),
+            syn::Expr::Cast(expr_cast) => {
+                let (signed, bits) = primitive(&expr_cast.ty)
+                    .and_then(int_type)
+                    .ok_or(ConstEvalError::Unsupported)?;
+                let value = self.evaluate(&expr_cast.expr, None)?;
+                wrap(value, signed, bits).ok_or(ConstEvalError::Unsupported)
+            }
+            syn::Expr::Path(expr_path) if expr_path.qself.is_none() => {
+                self.evaluate_path(&expr_path.path)
+            }
+            _ => Err(ConstEvalError::Unsupported),
+        }
+    }
+
+    fn evaluate_binary(
+        &mut self,
+        node: &syn::ExprBinary,
+        ty: Option<IntType>,
+    ) -> Result<i128, ConstEvalError> {
+        use syn::BinOp::*;
+
+        let left = self.evaluate(&node.left, ty)?;
+        // The amount of a shift can have any integer type
+        let right = match node.op {
+            Shl(..) | Shr(..) => self.evaluate(&node.right, None)?,
+            _ => self.evaluate(&node.right, ty)?,
+        };
+
+        let value = match node.op {
+            Add(..) => left.checked_add(right),
+            Sub(..) => left.checked_sub(right),
+            Mul(..) => left.checked_mul(right),
+            Div(..) | Rem(..) if right == 0 => return Err(ConstEvalError::DivisionByZero),
+            Div(..) => left.checked_div(right),
+            Rem(..) => left.checked_rem(right),
+            BitXor(..) => Some(left ^ right),
+            BitAnd(..) => Some(left & right),
+            BitOr(..) => Some(left | right),
+            Shl(..) | Shr(..) => {
+                let bits = ty.map(|(_, bits)| bits).unwrap_or(128);
+                if right < 0 || right >= bits as i128 {
+                    return Err(ConstEvalError::Overflow);
+                }
+                match (node.op, ty) {
+                    (Shl(..), Some((signed, bits))) => wrap(left << right, signed, bits),
+                    (Shl(..), None) => left.checked_shl(right as u32),
+                    _ => Some(left >> right),
+                }
+            }
+            _ => return Err(ConstEvalError::Unsupported),
+        };
+
+        // `u128` results above `i128::MAX` can't be computed, but are only overflows if they
+        // don't fit in a `u128` either
+        if value.is_none() && ty == Some((false, 128)) {
+            let (left, right) = (left as u128, right as u128);
+            let fits = match node.op {
+                Add(..) => left.checked_add(right).is_some(),
+                Mul(..) => left.checked_mul(right).is_some(),
+                Shl(..) => true,
+                _ => false,
+            };
+            if fits {
+                return Err(ConstEvalError::Unsupported);
+            }
+        }
+
+        value.ok_or(ConstEvalError::Overflow)
+    }
+
+    /// Evaluates references to constants in the file, and to the `MIN`, `MAX` and `BITS`
+    /// constants of integer types
+    fn evaluate_path(&mut self, path: &syn::Path) -> Result<i128, ConstEvalError> {
+        let segments: Vec<_> = path.segments.iter().map(|segment| &segment.ident).collect();
+
+        match &segments[..] {
+            [ident] => {
+                let name = ident.to_string();
+                if self.evaluating.contains(&name) {
+                    return Err(ConstEvalError::Cycle);
+                }
+                let item_const = match self.analyzer.find_const(&name) {
+                    Some(item_const) => item_const,
+                    None if self.analyzer.is_const_param(ident) => {
+                        return Err(ConstEvalError::Generic)
+                    }
+                    None => return Err(ConstEvalError::UnknownConst),
+                };
+
+                self.evaluating.push(name);
+                let ty = primitive(&item_const.ty).and_then(int_type);
+                let value = self.evaluate(&item_const.expr, ty);
+                self.evaluating.pop();
+                value
+            }
+            [ty, constant] => {
+                let (signed, bits) = primitive_named(&ty.to_string())
+                    .and_then(int_type)
+                    .ok_or(ConstEvalError::UnknownConst)?;
+                let (min, max) = range(signed, bits);
+                match constant.to_string().as_str() {
+                    "MAX" if !signed && bits == 128 => Err(ConstEvalError::Unsupported),
+                    "MIN" => Ok(min),
+                    "MAX" => Ok(max),
+                    "BITS" => Ok(bits as i128),
+                    _ => Err(ConstEvalError::UnknownConst),
+                }
+            }
+            _ => Err(ConstEvalError::UnknownConst),
+        }
+    }
+}
+
+/// The error for an integer literal that doesn't fit in an `i128`
+fn too_large(lit_int: &syn::LitInt) -> ConstEvalError {
+    match lit_int.base10_parse::<u128>() {
+        Ok(..) => ConstEvalError::Unsupported,
+        Err(..) => ConstEvalError::Overflow,
+    }
+}
+
+fn check(value: i128, ty: Option<IntType>) -> Result<i128, ConstEvalError> {
+    match ty {
+        Some((signed, bits)) => {
+            let (min, max) = range(signed, bits);
+            if value < min || value > max {
+                Err(ConstEvalError::Overflow)
+            } else {
+                Ok(value)
+            }
+        }
+        None => Ok(value),
+    }
+}
+
+/// The integer type in the `repr` attribute of an enum
+fn repr(node: &syn::ItemEnum) -> Option<IntType> {
+    node.attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|nested| match nested {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                primitive_named(&path.get_ident()?.to_string()).and_then(int_type)
+            }
+            _ => None,
+        })
+}
+
+/// Pointer sized integers are assumed to be 64 bits wide
+fn int_type(ty: Primitive) -> Option<IntType> {
+    match ty {
+        Primitive::Int { signed, bits } => Some((signed, bits.unwrap_or(64))),
+        _ => None,
+    }
+}
+
+fn generics<'a>(node: Syn<'a>) -> Option<&'a syn::Generics> {
+    match node {
+        Syn::ItemStruct(item) => Some(&item.generics),
+        Syn::ItemEnum(item) => Some(&item.generics),
+        Syn::ItemUnion(item) => Some(&item.generics),
+        Syn::ItemTrait(item) => Some(&item.generics),
+        Syn::ItemType(item) => Some(&item.generics),
+        Syn::ItemImpl(item) => Some(&item.generics),
+        Syn::ItemFn(item) => Some(&item.sig.generics),
+        Syn::ImplItemMethod(item) => Some(&item.sig.generics),
+        Syn::TraitItemMethod(item) => Some(&item.sig.generics),
+        _ => None,
+    }
+}
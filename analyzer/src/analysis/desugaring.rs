@@ -0,0 +1,281 @@
+use super::{printing::tokens_to_snippet, NodeAnalyzer};
+use crate::{help::HelpItem, syn_wrappers::Syn};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Attaches the equivalent desugared code to the explanation of syntactic sugar
+    pub(super) fn desugar(&mut self, node: Syn) {
+        let item = match &self.help {
+            Some((_, item)) => item,
+            None => return,
+        };
+
+        let tokens = match (item, node) {
+            (HelpItem::ExprTryQuestionMark { .. }, Syn::ExprTry(expr_try)) => {
+                Some(desugar_try(expr_try))
+            }
+            (HelpItem::ExprForLoopToken, Syn::ExprForLoop(for_loop)) => {
+                Some(desugar_for_loop(for_loop))
+            }
+            (HelpItem::ExprWhileLet, Syn::ExprWhile(expr_while)) => desugar_while_let(expr_while),
+            (HelpItem::AsyncFn, Syn::Signature(sig)) => desugar_async_fn(sig, self.fn_body()),
+            (HelpItem::TypeImplTrait, Syn::TypeImplTrait(..)) => self
+                .enclosing_signature()
+                .filter(|sig| self.within(&sig.inputs))
+                .and_then(desugar_impl_trait_args),
+            (HelpItem::ExprAssignOp, Syn::ExprAssignOp(assign_op)) => desugar_assign_op(assign_op),
+            (_, Syn::BinOp(..)) => get_ancestor![self, ExprAssignOp, 1].and_then(desugar_assign_op),
+            (HelpItem::ExprRangeHalfOpen { .. }, Syn::ExprRange(range))
+            | (HelpItem::ExprRangeClosed { .. }, Syn::ExprRange(range)) => {
+                Some(desugar_range(range))
+            }
+            (HelpItem::ExprStructRest, Syn::ExprStruct(expr_struct)) => {
+                self.desugar_struct_rest(expr_struct)
+            }
+            _ => None,
+        };
+
+        self.desugared = tokens.map(|tokens| tokens_to_snippet(&tokens));
+    }
+
+    fn fn_body(&self) -> Option<&'a syn::Block> {
+        match self.ancestors.last()?.1 {
+            Syn::ItemFn(item_fn) => Some(&item_fn.block),
+            Syn::ImplItemMethod(method) => Some(&method.block),
+            Syn::TraitItemMethod(method) => method.default.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Lists the fields taken from the base expression, if the struct is declared in the file
+    fn desugar_struct_rest(&self, node: &syn::ExprStruct) -> Option<TokenStream> {
+        let rest = node.rest.as_ref()?;
+        let item_struct = self.find_struct(&node.path.segments.last()?.ident.to_string())?;
+        let named = match &item_struct.fields {
+            syn::Fields::Named(named) => named,
+            _ => return None,
+        };
+
+        let explicit: Vec<_> = node.fields.iter().collect();
+        let remaining = named
+            .named
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .filter(|ident| {
+                !explicit
+                    .iter()
+                    .any(|field| std::matches!(&field.member, syn::Member::Named(member) if member == *ident))
+            });
+
+        let path = &node.path;
+        let taken = |base: TokenStream| -> Vec<TokenStream> {
+            remaining
+                .clone()
+                .map(|ident| quote! { #ident: #base.#ident })
+                .collect()
+        };
+
+        // The base expression is evaluated only once
+        Some(match &**rest {
+            syn::Expr::Path(..) => {
+                let taken = taken(quote! { #rest });
+                quote! { #path { #(#explicit,)* #(#taken,)* } }
+            }
+            _ => {
+                let taken = taken(quote! { base });
+                quote! {
+                    {
+                        let base = #rest;
+                        #path { #(#explicit,)* #(#taken,)* }
+                    }
+                }
+            }
+        })
+    }
+}
+
+fn desugar_try(node: &syn::ExprTry) -> TokenStream {
+    let expr = &node.expr;
+    quote! {
+        match Try::branch(#expr) {
+            ControlFlow::Continue(value) => value,
+            ControlFlow::Break(residual) => return FromResidual::from_residual(residual),
+        }
+    }
+}
+
+fn desugar_for_loop(node: &syn::ExprForLoop) -> TokenStream {
+    let syn::ExprForLoop {
+        label,
+        pat,
+        expr,
+        body,
+        ..
+    } = node;
+
+    quote! {
+        {
+            let mut iter = IntoIterator::into_iter(#expr);
+            #label loop {
+                match Iterator::next(&mut iter) {
+                    Some(#pat) => #body,
+                    None => break,
+                }
+            }
+        }
+    }
+}
+
+fn desugar_while_let(node: &syn::ExprWhile) -> Option<TokenStream> {
+    let (pat, expr) = match &*node.cond {
+        syn::Expr::Let(expr_let) => (&expr_let.pat, &expr_let.expr),
+        _ => return None,
+    };
+    let syn::ExprWhile { label, body, .. } = node;
+
+    Some(quote! {
+        #label loop {
+            match #expr {
+                #pat => #body,
+                _ => break,
+            }
+        }
+    })
+}
+
+fn desugar_async_fn(sig: &syn::Signature, body: Option<&syn::Block>) -> Option<TokenStream> {
+    let mut desugared = sig.clone();
+    desugared.asyncness = None;
+
+    let output = match &sig.output {
+        syn::ReturnType::Default => quote! { () },
+        syn::ReturnType::Type(_, ty) => quote! { #ty },
+    };
+    desugared.output = syn::parse_quote! { -> impl Future<Output = #output> };
+
+    Some(match body {
+        Some(body) => quote! { #desugared { async move #body } },
+        None => quote! { #desugared; },
+    })
+}
+
+/// Each `impl Trait` argument becomes an anonymous type parameter
+fn desugar_impl_trait_args(sig: &syn::Signature) -> Option<TokenStream> {
+    let mut desugared = sig.clone();
+
+    let declared: Vec<_> = sig
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+    let mut names = ["T", "U", "V", "W"]
+        .iter()
+        .map(|name| name.to_string())
+        .chain((1..).map(|n| format!("T{}", n)))
+        .map(|name| syn::Ident::new(&name, Span::call_site()))
+        .filter(|ident| !declared.contains(&ident));
+    let mut params: Vec<syn::TypeParam> = vec![];
+
+    for arg in desugared.inputs.iter_mut() {
+        if let syn::FnArg::Typed(pat_type) = arg {
+            replace_impl_traits(&mut pat_type.ty, &mut |bounds| {
+                let ident = names.next()?;
+                params.push(syn::parse_quote! { #ident: #bounds });
+                Some(syn::parse_quote! { #ident })
+            });
+        }
+    }
+
+    if params.is_empty() {
+        return None;
+    }
+
+    // Anonymous type parameters are declared after the explicit ones
+    desugared
+        .generics
+        .params
+        .extend(params.into_iter().map(syn::GenericParam::Type));
+    if desugared.generics.lt_token.is_none() {
+        desugared.generics.lt_token = Some(Default::default());
+        desugared.generics.gt_token = Some(Default::default());
+    }
+
+    Some(quote! { #desugared; })
+}
+
+fn replace_impl_traits(
+    ty: &mut syn::Type,
+    f: &mut dyn FnMut(
+        &syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]>,
+    ) -> Option<syn::Type>,
+) {
+    match ty {
+        syn::Type::ImplTrait(impl_trait) => {
+            if let Some(replacement) = f(&impl_trait.bounds) {
+                *ty = replacement;
+            }
+        }
+        syn::Type::Array(array) => replace_impl_traits(&mut array.elem, f),
+        syn::Type::Group(group) => replace_impl_traits(&mut group.elem, f),
+        syn::Type::Paren(paren) => replace_impl_traits(&mut paren.elem, f),
+        syn::Type::Path(type_path) => {
+            for segment in type_path.path.segments.iter_mut() {
+                if let syn::PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                    for argument in arguments.args.iter_mut() {
+                        if let syn::GenericArgument::Type(ty) = argument {
+                            replace_impl_traits(ty, f);
+                        }
+                    }
+                }
+            }
+        }
+        syn::Type::Ptr(ptr) => replace_impl_traits(&mut ptr.elem, f),
+        syn::Type::Reference(reference) => replace_impl_traits(&mut reference.elem, f),
+        syn::Type::Slice(slice) => replace_impl_traits(&mut slice.elem, f),
+        syn::Type::Tuple(tuple) => tuple
+            .elems
+            .iter_mut()
+            .for_each(|elem| replace_impl_traits(elem, f)),
+        _ => {}
+    }
+}
+
+fn desugar_assign_op(node: &syn::ExprAssignOp) -> Option<TokenStream> {
+    use syn::BinOp::*;
+    let (trait_, method) = match node.op {
+        AddEq(..) => ("AddAssign", "add_assign"),
+        SubEq(..) => ("SubAssign", "sub_assign"),
+        MulEq(..) => ("MulAssign", "mul_assign"),
+        DivEq(..) => ("DivAssign", "div_assign"),
+        RemEq(..) => ("RemAssign", "rem_assign"),
+        BitXorEq(..) => ("BitXorAssign", "bitxor_assign"),
+        BitAndEq(..) => ("BitAndAssign", "bitand_assign"),
+        BitOrEq(..) => ("BitOrAssign", "bitor_assign"),
+        ShlEq(..) => ("ShlAssign", "shl_assign"),
+        ShrEq(..) => ("ShrAssign", "shr_assign"),
+        _ => return None,
+    };
+    let trait_ = syn::Ident::new(trait_, Span::call_site());
+    let method = syn::Ident::new(method, Span::call_site());
+    let syn::ExprAssignOp { left, right, .. } = node;
+
+    Some(quote! { #trait_::#method(&mut #left, #right) })
+}
+
+fn desugar_range(node: &syn::ExprRange) -> TokenStream {
+    let syn::ExprRange { from, to, .. } = node;
+
+    match (&node.limits, from, to) {
+        (syn::RangeLimits::HalfOpen(..), Some(from), Some(to)) => {
+            quote! { Range { start: #from, end: #to } }
+        }
+        (syn::RangeLimits::HalfOpen(..), Some(from), None) => quote! { RangeFrom { start: #from } },
+        (syn::RangeLimits::HalfOpen(..), None, Some(to)) => quote! { RangeTo { end: #to } },
+        (syn::RangeLimits::HalfOpen(..), None, None) => quote! { RangeFull },
+        (syn::RangeLimits::Closed(..), Some(from), Some(to)) => {
+            quote! { RangeInclusive::new(#from, #to) }
+        }
+        (syn::RangeLimits::Closed(..), _, to) => quote! { RangeToInclusive { end: #to } },
+    }
+}
@@ -0,0 +1,235 @@
+use super::{printing::tokens_to_string, receiver_kind, NodeAnalyzer};
+use crate::{
+    help::{ElisionSource, HelpItem, SelfKind},
+    syn_wrappers::Syn,
+};
+use proc_macro2::Span;
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Explains the lifetime given to an elided reference in the return type of a function
+    pub(super) fn elided_lifetime_help(&self, node: &syn::TypeReference) -> Option<HelpItem> {
+        if !node.lifetime.as_ref().map(is_elided).unwrap_or(true) {
+            return None;
+        }
+
+        self.elision_help(tokens_to_string(node))
+    }
+
+    /// Explains an elided `'_` lifetime argument in the return type of a function, like in
+    /// `Ref<'_, T>`
+    pub(super) fn elided_lifetime_argument_help(&self, node: &syn::Lifetime) -> Option<HelpItem> {
+        if !is_elided(node) {
+            return None;
+        }
+
+        let type_path = self
+            .ancestors
+            .iter()
+            .rev()
+            .find_map(|(_, node)| match node {
+                Syn::TypePath(type_path) => Some(*type_path),
+                _ => None,
+            })?;
+
+        self.elision_help(tokens_to_string(type_path))
+    }
+
+    fn elision_help(&self, ty: String) -> Option<HelpItem> {
+        // Fn traits and function pointer types have their own elision scope
+        let sig = self.enclosing_signature()?;
+
+        if !self.within(&sig.output) {
+            return None;
+        }
+
+        let elision = elide(sig);
+
+        Some(HelpItem::ElidedLifetime {
+            ty,
+            source: elision.source,
+            input: elision.input,
+            lifetime: elision.lifetime.as_ref().map(|lf| lf.to_string()),
+            annotated: elision.annotated,
+        })
+    }
+}
+
+struct Elision {
+    source: ElisionSource,
+    /// The name of the argument the output borrows from
+    input: Option<String>,
+    lifetime: Option<syn::Lifetime>,
+    /// The signature with every elided lifetime written out
+    annotated: Option<String>,
+}
+
+/// Applies the lifetime elision rules to a function signature
+fn elide(sig: &syn::Signature) -> Elision {
+    let mut annotated = sig.clone();
+
+    let declared: Vec<_> = sig
+        .generics
+        .lifetimes()
+        .map(|def| def.lifetime.clone())
+        .collect();
+    let mut names = ('a'..='z')
+        .map(|c| syn::Lifetime::new(&format!("'{}", c), Span::call_site()))
+        .filter(|lf| !declared.contains(lf));
+    let mut fresh = vec![];
+
+    // Every elided lifetime in the inputs becomes a distinct lifetime parameter. Named lifetimes
+    // used by several inputs only count once, and are not attributed to a single argument
+    let mut inputs: Vec<(Option<String>, syn::Lifetime)> = vec![];
+    let mut push_input = |arg_name: Option<String>, lifetime: syn::Lifetime| match inputs
+        .iter_mut()
+        .find(|(_, other)| *other == lifetime)
+    {
+        Some((name, _)) if *name != arg_name => *name = None,
+        Some(..) => {}
+        None => inputs.push((arg_name, lifetime)),
+    };
+    for arg in annotated.inputs.iter_mut() {
+        let mut name = |lf: &mut syn::Lifetime| {
+            if is_elided(lf) {
+                *lf = names.next().unwrap_or_else(|| lf.clone());
+                fresh.push(lf.clone());
+            }
+        };
+
+        match arg {
+            syn::FnArg::Receiver(receiver) => {
+                if let Some((_, lifetime)) = &mut receiver.reference {
+                    let lifetime = lifetime.get_or_insert_with(underscore);
+                    name(lifetime);
+                    push_input(Some("self".to_string()), lifetime.clone());
+                }
+            }
+            syn::FnArg::Typed(pat_type) => {
+                let arg_name = match &*pat_type.pat {
+                    syn::Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+                    _ => None,
+                };
+                visit_lifetimes(&mut pat_type.ty, &mut |lf| {
+                    name(lf);
+                    push_input(arg_name.clone(), lf.clone());
+                });
+            }
+        }
+    }
+
+    let source = match receiver_kind(sig) {
+        Some(SelfKind::Ref) | Some(SelfKind::RefMut) => ElisionSource::SelfRef,
+        _ => match inputs.len() {
+            0 => ElisionSource::NoInputs,
+            1 => ElisionSource::OnlyInput,
+            _ => ElisionSource::Ambiguous,
+        },
+    };
+
+    let (input, lifetime) = match source {
+        ElisionSource::SelfRef | ElisionSource::OnlyInput => inputs.swap_remove(0),
+        _ => {
+            return Elision {
+                source,
+                input: None,
+                lifetime: None,
+                annotated: None,
+            }
+        }
+    };
+
+    if let syn::ReturnType::Type(_, ty) = &mut annotated.output {
+        visit_lifetimes(ty, &mut |lf| {
+            if is_elided(lf) {
+                *lf = lifetime.clone();
+            }
+        });
+    }
+
+    // Lifetime parameters must be declared before any other generic parameter
+    for (i, lifetime) in fresh.into_iter().enumerate() {
+        annotated.generics.params.insert(
+            i,
+            syn::GenericParam::Lifetime(syn::LifetimeDef::new(lifetime)),
+        );
+    }
+    if annotated.generics.lt_token.is_none() {
+        annotated.generics.lt_token = Some(Default::default());
+        annotated.generics.gt_token = Some(Default::default());
+    }
+
+    Elision {
+        source,
+        input,
+        lifetime: Some(lifetime),
+        annotated: Some(tokens_to_string(&annotated)),
+    }
+}
+
+fn underscore() -> syn::Lifetime {
+    syn::Lifetime::new("'_", Span::call_site())
+}
+
+fn is_elided(lifetime: &syn::Lifetime) -> bool {
+    lifetime.ident == "_"
+}
+
+/// Calls `f` on every lifetime in `ty`, in source order.
+///
+/// Elided lifetimes of references are made explicit as `'_` first. Function pointer types are not
+/// visited, because they have their own elision scope.
+fn visit_lifetimes(ty: &mut syn::Type, f: &mut dyn FnMut(&mut syn::Lifetime)) {
+    match ty {
+        syn::Type::Array(array) => visit_lifetimes(&mut array.elem, f),
+        syn::Type::Group(group) => visit_lifetimes(&mut group.elem, f),
+        syn::Type::ImplTrait(impl_trait) => visit_bounds_lifetimes(&mut impl_trait.bounds, f),
+        syn::Type::Paren(paren) => visit_lifetimes(&mut paren.elem, f),
+        syn::Type::Path(type_path) => {
+            if let Some(qself) = &mut type_path.qself {
+                visit_lifetimes(&mut qself.ty, f);
+            }
+            visit_path_lifetimes(&mut type_path.path, f);
+        }
+        syn::Type::Ptr(ptr) => visit_lifetimes(&mut ptr.elem, f),
+        syn::Type::Reference(reference) => {
+            f(reference.lifetime.get_or_insert_with(underscore));
+            visit_lifetimes(&mut reference.elem, f);
+        }
+        syn::Type::Slice(slice) => visit_lifetimes(&mut slice.elem, f),
+        syn::Type::TraitObject(trait_object) => visit_bounds_lifetimes(&mut trait_object.bounds, f),
+        syn::Type::Tuple(tuple) => tuple
+            .elems
+            .iter_mut()
+            .for_each(|elem| visit_lifetimes(elem, f)),
+        _ => {}
+    }
+}
+
+fn visit_bounds_lifetimes<P>(
+    bounds: &mut syn::punctuated::Punctuated<syn::TypeParamBound, P>,
+    f: &mut dyn FnMut(&mut syn::Lifetime),
+) {
+    for bound in bounds.iter_mut() {
+        match bound {
+            syn::TypeParamBound::Trait(trait_bound) => {
+                visit_path_lifetimes(&mut trait_bound.path, f)
+            }
+            syn::TypeParamBound::Lifetime(lifetime) => f(lifetime),
+        }
+    }
+}
+
+fn visit_path_lifetimes(path: &mut syn::Path, f: &mut dyn FnMut(&mut syn::Lifetime)) {
+    for segment in path.segments.iter_mut() {
+        if let syn::PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+            for argument in arguments.args.iter_mut() {
+                match argument {
+                    syn::GenericArgument::Lifetime(lifetime) => f(lifetime),
+                    syn::GenericArgument::Type(ty) => visit_lifetimes(ty, f),
+                    syn::GenericArgument::Binding(binding) => visit_lifetimes(&mut binding.ty, f),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use super::NodeAnalyzer;
+use crate::{ir::Range, syn_wrappers::Syn};
+use proc_macro2::Span;
+use syn::{punctuated::Punctuated, spanned::Spanned, visit::Visit};
+
+/// Macros that never return to their caller
+const DIVERGING_MACROS: &[&str] = &["panic", "todo", "unimplemented", "unreachable"];
+
+/// A point where control leaves a function
+pub struct ExitPoint {
+    pub range: Range,
+    /// Short description, like `return` or `panic!`
+    pub label: String,
+}
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Every exit point of the function whose `fn` keyword or `->` arrow is at the current
+    /// location
+    pub(super) fn exit_points(&self, node: Syn) -> Option<Vec<ExitPoint>> {
+        let (sig, block) = match node {
+            Syn::ItemFn(item_fn) => (&item_fn.sig, &*item_fn.block),
+            Syn::ImplItemMethod(method) => (&method.sig, &method.block),
+            Syn::TraitItemMethod(method) => (&method.sig, method.default.as_ref()?),
+            _ => return None,
+        };
+
+        let on_rarrow = match &sig.output {
+            syn::ReturnType::Type(rarrow, _) => self.within(rarrow),
+            syn::ReturnType::Default => false,
+        };
+        if !self.within(sig.fn_token) && !on_rarrow {
+            return None;
+        }
+
+        let mut collector = ExitCollector { exits: vec![] };
+        collector.visit_block(block);
+
+        let tail = match block.stmts.last() {
+            Some(syn::Stmt::Expr(expr)) => Some(expr),
+            _ => None,
+        };
+        match tail {
+            // Already collected
+            Some(syn::Expr::Return(..)) => {}
+            Some(syn::Expr::Macro(expr_macro)) if is_diverging_macro(&expr_macro.mac) => {}
+            // Never completes, so its value is never returned
+            Some(syn::Expr::Loop(expr_loop)) if !breaks(expr_loop) => {}
+            Some(expr) => {
+                // Calls like `process::exit(..)` are already collected with their own label
+                let span = expr.span();
+                let range = (span.start().into(), span.end().into());
+                if !collector.exits.iter().any(|exit| exit.range == range) {
+                    collector.push(span, "tail expression");
+                }
+            }
+            None if !block.stmts.last().map(diverges).unwrap_or(false) => {
+                let end = block.brace_token.span.end();
+                let start = proc_macro2::LineColumn {
+                    column: end.column.saturating_sub(1),
+                    ..end
+                };
+                collector.exits.push(ExitPoint {
+                    range: (start.into(), end.into()),
+                    label: "end of body".to_string(),
+                });
+            }
+            None => {}
+        }
+
+        let mut exits = collector.exits;
+        exits.sort_by_key(|exit| exit.range);
+        Some(exits)
+    }
+}
+
+struct ExitCollector {
+    exits: Vec<ExitPoint>,
+}
+
+impl ExitCollector {
+    fn push(&mut self, span: Span, label: &str) {
+        self.exits.push(ExitPoint {
+            range: (span.start().into(), span.end().into()),
+            label: label.to_string(),
+        });
+    }
+}
+
+impl<'ast> Visit<'ast> for ExitCollector {
+    fn visit_expr_return(&mut self, node: &'ast syn::ExprReturn) {
+        self.push(node.span(), "return");
+        syn::visit::visit_expr_return(self, node);
+    }
+
+    fn visit_expr_try(&mut self, node: &'ast syn::ExprTry) {
+        self.push(node.question_token.span(), "?");
+        syn::visit::visit_expr_try(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+        if let Some(function) = process_exit(node) {
+            self.push(node.span(), &format!("process::{}", function));
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        if is_diverging_macro(node) {
+            let name = node.path.segments.last().map(|segment| &segment.ident);
+            if let Some(name) = name {
+                self.push(node.span(), &format!("{}!", name));
+            }
+        }
+
+        // The arguments of most macros (like `println!`) are expressions, which can contain `?`
+        // or `return`
+        let args = node.parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated);
+        if let Ok(args) = args {
+            args.iter().for_each(|arg| self.visit_expr(arg));
+        }
+    }
+
+    // Returns and `?` in these belong to a different function, or to the block itself
+    fn visit_expr_closure(&mut self, _: &'ast syn::ExprClosure) {}
+    fn visit_expr_async(&mut self, _: &'ast syn::ExprAsync) {}
+    fn visit_expr_try_block(&mut self, _: &'ast syn::ExprTryBlock) {}
+    fn visit_item(&mut self, node: &'ast syn::Item) {
+        // Macros in statement position are parsed as items
+        if let syn::Item::Macro(item_macro) = node {
+            self.visit_macro(&item_macro.mac);
+        }
+    }
+}
+
+fn is_diverging_macro(mac: &syn::Macro) -> bool {
+    mac.path
+        .segments
+        .last()
+        .map(|segment| DIVERGING_MACROS.contains(&segment.ident.to_string().as_str()))
+        .unwrap_or(false)
+}
+
+/// The name of the function if the call is `process::exit(..)` or `process::abort()`
+fn process_exit(call: &syn::ExprCall) -> Option<String> {
+    let expr_path = match &*call.func {
+        syn::Expr::Path(expr_path) => expr_path,
+        _ => return None,
+    };
+    let segments: Vec<_> = expr_path
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    match &segments[..] {
+        [.., module, function]
+            if module == "process" && (function == "exit" || function == "abort") =>
+        {
+            Some(function.clone())
+        }
+        _ => None,
+    }
+}
+
+/// Whether the statement never completes, so there's no implicit return after it
+fn diverges(stmt: &syn::Stmt) -> bool {
+    match stmt {
+        syn::Stmt::Semi(syn::Expr::Return(..), _) => true,
+        syn::Stmt::Expr(syn::Expr::Loop(expr_loop))
+        | syn::Stmt::Semi(syn::Expr::Loop(expr_loop), _) => !breaks(expr_loop),
+        syn::Stmt::Semi(syn::Expr::Macro(expr_macro), _) => is_diverging_macro(&expr_macro.mac),
+        syn::Stmt::Semi(syn::Expr::Call(call), _) => process_exit(call).is_some(),
+        syn::Stmt::Item(syn::Item::Macro(item_macro)) => is_diverging_macro(&item_macro.mac),
+        _ => false,
+    }
+}
+
+/// Whether a `break` can leave the loop
+fn breaks(expr_loop: &syn::ExprLoop) -> bool {
+    let mut finder = BreakFinder {
+        label: expr_loop.label.as_ref().map(|label| &label.name),
+        nested: 0,
+        found: false,
+    };
+    finder.visit_block(&expr_loop.body);
+    finder.found
+}
+
+struct BreakFinder<'l> {
+    label: Option<&'l syn::Lifetime>,
+    /// How many loops inside the searched one enclose the current node
+    nested: usize,
+    found: bool,
+}
+
+impl<'l> BreakFinder<'l> {
+    fn nested_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.nested += 1;
+        visit(self);
+        self.nested -= 1;
+    }
+}
+
+impl<'ast, 'l> Visit<'ast> for BreakFinder<'l> {
+    fn visit_expr_break(&mut self, node: &'ast syn::ExprBreak) {
+        self.found |= match &node.label {
+            Some(label) => Some(label) == self.label,
+            None => self.nested == 0,
+        };
+        syn::visit::visit_expr_break(self, node);
+    }
+
+    fn visit_expr_loop(&mut self, node: &'ast syn::ExprLoop) {
+        self.nested_loop(|finder| syn::visit::visit_expr_loop(finder, node));
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast syn::ExprWhile) {
+        self.nested_loop(|finder| syn::visit::visit_expr_while(finder, node));
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast syn::ExprForLoop) {
+        self.nested_loop(|finder| syn::visit::visit_expr_for_loop(finder, node));
+    }
+
+    // A `break` in these can't leave the loop
+    fn visit_expr_closure(&mut self, _: &'ast syn::ExprClosure) {}
+    fn visit_expr_async(&mut self, _: &'ast syn::ExprAsync) {}
+    fn visit_item(&mut self, _: &'ast syn::Item) {}
+}
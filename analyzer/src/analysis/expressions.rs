@@ -1,5 +1,9 @@
-use super::NodeAnalyzer;
-use crate::help::{LoopOf, ReturnOf};
+use super::resolution::{
+    is_capitalized, same_type, MethodDefinition, PRELUDE_FUNCTIONS, PRELUDE_VARIANTS,
+};
+use super::{casts::primitive_named, printing::tokens_to_string};
+use super::{receiver_kind, NodeAnalyzer};
+use crate::help::{BindingOf, CallOf, LoopOf, ReturnOf};
 use crate::{
     syn_wrappers::{Syn, SynKind},
     HelpItem,
@@ -42,14 +46,30 @@ impl<'a> NodeAnalyzer<'a> {
             },
         );
     }
+    pub(super) fn visit_expr_call(&mut self, node: &syn::ExprCall) {
+        let item = match &*node.func {
+            syn::Expr::Path(expr_path) if expr_path.qself.is_none() => {
+                self.call_help(&expr_path.path)
+            }
+            func => HelpItem::ExprCall {
+                of: CallOf::Expression,
+                name: func.to_token_stream().to_string(),
+                parent: None,
+                in_file: false,
+            },
+        };
+
+        // TODO: see [HITBOX]
+        return self.set_help(node, item);
+    }
     pub(super) fn visit_expr_cast(&mut self, node: &syn::ExprCast) {
-        token![self, node.as_token, AsCast];
+        token![self, node.as_token, *self.cast_help(node)];
     }
     pub(super) fn visit_expr_closure(&mut self, node: &syn::ExprClosure) {
-        token![self, node.or1_token, ExprClosureArguments];
-        token![self, node.or2_token, ExprClosureArguments];
+        token![self, node.or1_token, *self.closure_help(node, false)];
+        token![self, node.or2_token, *self.closure_help(node, false)];
         token![self, some node.asyncness, ExprClosureAsync];
-        token![self, some node.capture, ExprClosureMove];
+        token![self, some node.capture, *self.closure_help(node, true)];
         token![self, some node.movability, ExprClosureStatic];
         return self.set_help(node, HelpItem::ExprClosure);
     }
@@ -62,6 +82,13 @@ impl<'a> NodeAnalyzer<'a> {
         );
     }
     pub(super) fn visit_expr_field(&mut self, node: &syn::ExprField) {
+        if let syn::Member::Named(ident) = &node.member {
+            if self.within(ident) {
+                if let Some(help) = self.field_access_privacy_violation(ident) {
+                    return self.set_help(ident, help);
+                }
+            }
+        }
         if let syn::Member::Unnamed(..) = node.member {
             return self.set_help_between(
                 node.dot_token.span(),
@@ -95,7 +122,9 @@ impl<'a> NodeAnalyzer<'a> {
                         },
                     );
                 }
-                _ => {}
+                pat => {
+                    self.visit_irrefutable_pat_first_pass(pat, BindingOf::ForLoop, Some(&node.expr))
+                }
             }
         }
     }
@@ -128,7 +157,45 @@ impl<'a> NodeAnalyzer<'a> {
         token![self, node.loop_token, ExprLoopToken];
     }
     pub(super) fn visit_expr_match(&mut self, node: &syn::ExprMatch) {
-        token![self, node.match_token, ExprMatchToken];
+        token![self, node.match_token, *self.match_token_help(node)];
+    }
+    pub(super) fn visit_expr_method_call(&mut self, node: &syn::ExprMethodCall) {
+        if !self.between(&node.dot_token, &node.method) {
+            return;
+        }
+
+        // Without the type of the receiver, any method with the same name could be the one called
+        let candidates = self.find_methods(&node.method);
+        let definition = self.receiver_types(&node.receiver).iter().find_map(|ty| {
+            let implemented = |method: &&MethodDefinition| match (method.impl_, method.trait_) {
+                (Some(item), _) => same_type(item, ty),
+                (_, Some(item)) => self.implements(item, ty),
+                _ => false,
+            };
+            candidates.iter().find(implemented)
+        });
+
+        let item = HelpItem::ExprMethodCall {
+            method: node.method.to_string(),
+            self_ty: definition
+                .and_then(|method| method.impl_)
+                .map(|item| tokens_to_string(&item.self_ty)),
+            trait_: definition.and_then(|method| {
+                method
+                    .trait_
+                    .map(|item| item.ident.to_string())
+                    .or_else(|| {
+                        method
+                            .impl_
+                            .and_then(|item| item.trait_.as_ref())
+                            .map(|(_, path, _)| tokens_to_string(path))
+                    })
+            }),
+            receiver: definition.and_then(|method| receiver_kind(method.sig)),
+            line: definition.map(|method| method.sig.ident.span().start().line),
+        };
+
+        return self.set_help_between(node.dot_token.span(), node.method.span(), item);
     }
     pub(super) fn visit_expr_range(&mut self, node: &syn::ExprRange) {
         let from = node.from.is_some();
@@ -154,10 +221,13 @@ impl<'a> NodeAnalyzer<'a> {
         return self.set_help(node, item);
     }
     pub(super) fn visit_expr_repeat(&mut self, node: &syn::ExprRepeat) {
+        let (value, unevaluated) = self.evaluate_const(&node.len, primitive_named("usize"));
         return self.set_help(
             node,
             HelpItem::ExprRepeat {
-                len: (&*node.len).to_token_stream().to_string(),
+                len: tokens_to_string(&node.len),
+                value,
+                unevaluated,
             },
         );
     }
@@ -189,11 +259,17 @@ impl<'a> NodeAnalyzer<'a> {
             }
         }
 
+        if self.within(&node.path) {
+            if let Some(help) = self.constructor_privacy_violation(&node.path) {
+                return self.set_help(&node.path, help);
+            }
+        }
+
         // TODO: see [HITBOX]. Used to have only the path as clickable
         return self.set_help(node, HelpItem::ExprStruct);
     }
     pub(super) fn visit_expr_try(&mut self, node: &syn::ExprTry) {
-        token![self, node.question_token, ExprTryQuestionMark];
+        token![self, node.question_token, *self.question_mark_help()];
     }
     pub(super) fn visit_expr_try_block(&mut self, node: &syn::ExprTryBlock) {
         token![self, node.try_token, ExprTryBlock];
@@ -214,7 +290,11 @@ impl<'a> NodeAnalyzer<'a> {
         return self.set_help(node, HelpItem::ExprType);
     }
     pub(super) fn visit_expr_unsafe(&mut self, node: &syn::ExprUnsafe) {
-        token![self, node.unsafe_token, ExprUnsafe];
+        token![
+            self,
+            node.unsafe_token,
+            *self.unsafe_block_help(Syn::ExprUnsafe(node))
+        ];
     }
     pub(super) fn visit_expr_while(&mut self, node: &syn::ExprWhile) {
         let let_token = if let syn::Expr::Let(syn::ExprLet { let_token, .. }) = *node.cond {
@@ -240,3 +320,207 @@ impl<'a> NodeAnalyzer<'a> {
         token![self, node.yield_token, ExprYield];
     }
 }
+
+impl<'a> NodeAnalyzer<'a> {
+    fn call_help(&self, path: &syn::Path) -> HelpItem {
+        let call = |of, name: &dyn ToString, parent: Option<String>, in_file| HelpItem::ExprCall {
+            of,
+            name: name.to_string(),
+            parent,
+            in_file,
+        };
+        let segments: Vec<_> = path.segments.iter().map(|s| &s.ident).collect();
+
+        match &segments[..] {
+            [ident] if path.leading_colon.is_none() => {
+                if let Some(local) = self.find_local(ident) {
+                    let of = match (local.init, local.ty) {
+                        (Some(syn::Expr::Closure(..)), _) => CallOf::Closure,
+                        (_, Some(syn::Type::BareFn(..))) => CallOf::FnPointer,
+                        _ => CallOf::Local,
+                    };
+                    return call(of, &local.pat.ident, None, true);
+                }
+
+                let name = if *ident == "Self" {
+                    match self.enclosing_self_ty().and_then(type_name) {
+                        Some(name) => name,
+                        None => return call(CallOf::TupleStruct, ident, None, false),
+                    }
+                } else {
+                    ident.to_string()
+                };
+
+                if let Some(item) = self.find_struct(&name) {
+                    if let syn::Fields::Unnamed(..) = item.fields {
+                        return call(CallOf::TupleStruct, &name, None, true);
+                    }
+                }
+                if self.find_fn(&name).is_some() {
+                    return call(CallOf::Function, &name, None, true);
+                }
+                if let Some(&(_, parent)) = PRELUDE_VARIANTS.iter().find(|(v, _)| *v == name) {
+                    return call(CallOf::TupleVariant, &name, Some(parent.to_string()), false);
+                }
+                if PRELUDE_FUNCTIONS.contains(&&name[..]) {
+                    return call(CallOf::Function, &name, None, false);
+                }
+                if is_capitalized(ident) {
+                    return call(CallOf::TupleStruct, &name, None, false);
+                }
+
+                call(CallOf::Function, &name, None, false)
+            }
+            [.., parent, ident] => {
+                let parent = if *parent == "Self" {
+                    self.enclosing_self_ty()
+                        .and_then(type_name)
+                        .unwrap_or_else(|| parent.to_string())
+                } else {
+                    parent.to_string()
+                };
+
+                if let Some(item_enum) = self.find_enum(&parent) {
+                    if item_enum.variants.iter().any(|v| &v.ident == *ident) {
+                        return call(CallOf::TupleVariant, ident, Some(parent), true);
+                    }
+                }
+                if PRELUDE_VARIANTS.contains(&(&ident.to_string()[..], &parent[..])) {
+                    return call(CallOf::TupleVariant, ident, Some(parent), false);
+                }
+                if self.is_type_name(&parent) {
+                    return call(CallOf::AssociatedFunction, ident, Some(parent), true);
+                }
+                if parent.starts_with(char::is_uppercase) || PRIMITIVES.contains(&&parent[..]) {
+                    return call(CallOf::AssociatedFunction, ident, Some(parent), false);
+                }
+
+                call(CallOf::Function, &full_path(path), None, false)
+            }
+            _ => call(CallOf::Function, &full_path(path), None, false),
+        }
+    }
+
+    /// The type of a method receiver, if it can be inferred syntactically, followed by the types
+    /// it dereferences to
+    pub(super) fn receiver_types(&self, receiver: &syn::Expr) -> Vec<syn::PathSegment> {
+        match receiver {
+            syn::Expr::Path(expr_path) => {
+                let ident = match expr_path.path.get_ident() {
+                    Some(ident) => ident,
+                    None => return vec![],
+                };
+                if ident == "self" {
+                    return self
+                        .enclosing_self_ty()
+                        .map(deref_chain)
+                        .unwrap_or_default();
+                }
+                match self.find_local(ident) {
+                    Some(local) => match (local.ty, local.init) {
+                        (Some(ty), _) => deref_chain(ty),
+                        (None, Some(init)) => constructed_type(init).into_iter().collect(),
+                        (None, None) => vec![],
+                    },
+                    // Unit structs
+                    None => constructed_type(receiver).into_iter().collect(),
+                }
+            }
+            syn::Expr::Reference(expr_ref) => self.receiver_types(&expr_ref.expr),
+            syn::Expr::Paren(expr_paren) => self.receiver_types(&expr_paren.expr),
+            expr => constructed_type(expr).into_iter().collect(),
+        }
+    }
+}
+
+/// Types that dereference to their type argument
+const SMART_POINTERS: &[&str] = &["Arc", "Box", "Pin", "Rc"];
+
+const PRIMITIVES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32",
+    "f64", "bool", "char", "str",
+];
+
+fn full_path(path: &syn::Path) -> String {
+    let segments = path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+
+    if path.leading_colon.is_some() {
+        format!("::{}", segments)
+    } else {
+        segments
+    }
+}
+
+pub(super) fn type_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        syn::Type::Reference(type_ref) => type_name(&type_ref.elem),
+        _ => None,
+    }
+}
+
+/// The last segment of the path of a type, followed by the ones of the types it dereferences to,
+/// like `Foo` for `Box<Foo>`
+fn deref_chain(ty: &syn::Type) -> Vec<syn::PathSegment> {
+    match ty {
+        syn::Type::Paren(syn::TypeParen { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. })
+        | syn::Type::Reference(syn::TypeReference { elem, .. }) => deref_chain(elem),
+        syn::Type::Path(type_path) => {
+            let segment = match type_path.path.segments.last() {
+                Some(segment) => segment,
+                None => return vec![],
+            };
+            let target = match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments)
+                    if SMART_POINTERS.contains(&segment.ident.to_string().as_str()) =>
+                {
+                    arguments.args.iter().find_map(|argument| match argument {
+                        syn::GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            };
+
+            let mut chain = vec![segment.clone()];
+            chain.extend(target.map(deref_chain).unwrap_or_default());
+            chain
+        }
+        _ => vec![],
+    }
+}
+
+/// The type built by a struct expression or a constructor-like call, e.g. `Foo { .. }`, `Foo(..)`
+/// or `Foo::new()`
+fn constructed_type(expr: &syn::Expr) -> Option<syn::PathSegment> {
+    let path = match expr {
+        syn::Expr::Struct(expr_struct) => &expr_struct.path,
+        syn::Expr::Call(syn::ExprCall { func, .. }) => match &**func {
+            syn::Expr::Path(expr_path) => &expr_path.path,
+            _ => return None,
+        },
+        // Unit structs and variants
+        syn::Expr::Path(expr_path) => &expr_path.path,
+        _ => return None,
+    };
+
+    // `Type::Variant(..)`, `Type::new()` or `module::Type(..)`
+    let mut segments = path.segments.iter().rev().map(|s| &s.ident);
+    let last = segments.next()?;
+    let ty = segments
+        .next()
+        .filter(|ident| is_capitalized(ident))
+        .unwrap_or(last);
+
+    Some(ty.clone().into()).filter(|_| is_capitalized(ty))
+}
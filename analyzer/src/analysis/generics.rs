@@ -5,6 +5,7 @@ use crate::{
     syn_wrappers::{Syn, SynKind},
 };
 use quote::ToTokens;
+use syn::spanned::Spanned;
 
 const DISTANCE_TYPE_PARAM_TO_CONTAINER: usize = 3;
 
@@ -100,6 +101,32 @@ impl<'a> NodeAnalyzer<'a> {
         }
     }
 
+    pub(super) fn visit_lifetime_def(&mut self, node: &syn::LifetimeDef) {
+        if let (Some(colon), false) = (node.colon_token, node.bounds.is_empty()) {
+            if self.between(&colon, &node.bounds) {
+                return self.set_help_between(
+                    colon.span(),
+                    node.bounds.span(),
+                    HelpItem::PredicateLifetime {
+                        lifetime: node.lifetime.to_string(),
+                        bounds: node.bounds.iter().map(|lf| lf.to_string()).collect(),
+                    },
+                );
+            }
+        }
+    }
+    pub(super) fn visit_predicate_eq(&mut self, node: &syn::PredicateEq) {
+        return self.set_help(node, HelpItem::PredicateEq);
+    }
+    pub(super) fn visit_predicate_lifetime(&mut self, node: &syn::PredicateLifetime) {
+        return self.set_help(
+            node,
+            HelpItem::PredicateLifetime {
+                lifetime: node.lifetime.to_string(),
+                bounds: node.bounds.iter().map(|lf| lf.to_string()).collect(),
+            },
+        );
+    }
     pub(super) fn visit_predicate_type_first_pass(&mut self, node: &syn::PredicateType) {
         let lifetimes = node
             .lifetimes
@@ -135,6 +162,19 @@ impl<'a> NodeAnalyzer<'a> {
         token![self, node.where_token, WhereClause];
     }
 
+    /// The bounded type, if the current node is a lifetime bound on a type, e.g. `T: 'a`
+    pub(super) fn lifetime_bounded_type(&self) -> Option<String> {
+        if !self.has_ancestor(1, SynKind::TypeParamBound) {
+            return None;
+        }
+
+        if let Some(predicate) = get_ancestor![self, PredicateType, 2] {
+            return Some(predicate.bounded_ty.to_token_stream().to_string());
+        }
+
+        get_ancestor![self, TypeParam, 2].map(|param| param.ident.to_string())
+    }
+
     fn find_containing_generics(&self) -> Option<Syn> {
         self.find_containing_generics_at(DISTANCE_TYPE_PARAM_TO_CONTAINER)
     }
@@ -1,4 +1,9 @@
-use super::NodeAnalyzer;
+use super::{
+    casts::{primitive, Primitive},
+    expressions::type_name,
+    printing::tokens_to_string,
+    NodeAnalyzer,
+};
 use crate::help::HelpItem;
 use proc_macro2::Span;
 use syn::spanned::Spanned;
@@ -11,7 +16,19 @@ macro_rules! fill_generics {
 
 impl<'a> NodeAnalyzer<'a> {
     pub(super) fn visit_item_const(&mut self, node: &syn::ItemConst) {
-        token![self, node.const_token, ItemConst];
+        token![self, node.const_token, *self.item_const_help(node)];
+    }
+    /// Only integer constants are evaluated
+    fn item_const_help(&self, node: &syn::ItemConst) -> HelpItem {
+        let (value, unevaluated) = match primitive(&node.ty) {
+            ty @ Some(Primitive::Int { .. }) => self.evaluate_const(&node.expr, ty),
+            _ => (None, None),
+        };
+        HelpItem::ItemConst {
+            value,
+            unevaluated,
+            visibility: self.item_visibility(&node.vis, &node.ident),
+        }
     }
     pub(super) fn visit_item_enum_first_pass(&mut self, node: &syn::ItemEnum) {
         fill_generics![self, node];
@@ -19,9 +36,26 @@ impl<'a> NodeAnalyzer<'a> {
     pub(super) fn visit_item_enum(&mut self, node: &syn::ItemEnum) {
         token![self, node.enum_token => node.ident, * HelpItem::ItemEnum {
             empty: node.variants.is_empty(),
-            generic: self.generics_for(self.id).is_some()
+            generic: self.generics_for(self.id).is_some(),
+            discriminants: self.discriminant_table(node),
+            visibility: self.item_visibility(&node.vis, &node.ident),
         }];
     }
+    fn discriminant_table(&self, node: &syn::ItemEnum) -> Vec<String> {
+        let discriminants = match self.discriminants(node) {
+            Some(discriminants) => discriminants,
+            None => return vec![],
+        };
+
+        node.variants
+            .iter()
+            .zip(discriminants)
+            .map(|(variant, discriminant)| match discriminant {
+                Ok(value) => format!("{} = {}", variant.ident, value),
+                Err(..) => format!("{} = ?", variant.ident),
+            })
+            .collect()
+    }
     pub(super) fn visit_item_extern_crate(&mut self, node: &syn::ItemExternCrate) {
         if let Some((as_token, ref rename)) = node.rename {
             token![self, as_token => rename, * HelpItem::AsRenameExternCrate { underscore: rename == "_" }];
@@ -32,7 +66,9 @@ impl<'a> NodeAnalyzer<'a> {
         self.fill_generics_info(self.id, (&node.sig.generics).into(), true);
     }
     pub(super) fn visit_item_fn(&mut self, node: &syn::ItemFn) {
-        token![self, node.sig.fn_token => node.sig.ident, ItemFn];
+        token![self, node.sig.fn_token => node.sig.ident, * HelpItem::ItemFn {
+            visibility: self.item_visibility(&node.vis, &node.sig.ident),
+        }];
     }
     pub(super) fn visit_item_foreign_mod(&mut self, node: &syn::ItemForeignMod) {
         token![self, node.abi, ItemForeignModAbi];
@@ -42,21 +78,146 @@ impl<'a> NodeAnalyzer<'a> {
     }
     pub(super) fn visit_item_impl(&mut self, node: &syn::ItemImpl) {
         token![self, some node.unsafety, ItemUnsafeImpl];
-        if let Some((_, _, for_token)) = node.trait_ {
-            token![self, for_token, ItemImplForTrait];
+        if let Some((_, ref path, for_token)) = node.trait_ {
+            token![self, for_token, *self.impl_for_trait_help(node, path)];
         }
-        token![
-            self,
-            node.impl_token,
-            *HelpItem::ItemImpl {
-                trait_: node.trait_.is_some(),
-                negative: node
-                    .trait_
-                    .as_ref()
-                    .and_then(|(bang, _, _)| bang.as_ref())
-                    .is_some()
+        if let Some((Some(bang), ref path, _)) = node.trait_ {
+            token![
+                self,
+                bang,
+                *HelpItem::ItemImplNegative {
+                    trait_: tokens_to_string(path),
+                    self_ty: tokens_to_string(&node.self_ty),
+                }
+            ];
+        }
+        token![self, node.impl_token, *self.item_impl_help(node)];
+    }
+    /// Classifies the implementation, and checks it against the orphan rule using the items
+    /// declared in the file
+    fn item_impl_help(&self, node: &syn::ItemImpl) -> HelpItem {
+        let trait_path = node.trait_.as_ref().map(|(_, path, _)| path);
+        let local_trait = trait_path
+            .and_then(|path| self.resolve_trait(path))
+            .is_some();
+
+        // The trait arguments count as well, e.g. `impl From<Local> for Vec<u8>`
+        let trait_args = trait_path
+            .and_then(|path| path.segments.last())
+            .into_iter()
+            .flat_map(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => arguments.args.iter().collect(),
+                _ => vec![],
+            })
+            .filter_map(|argument| match argument {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+        let local_type = std::iter::once(&*node.self_ty)
+            .chain(trait_args)
+            .filter_map(fundamental_type_name)
+            .any(|name| self.is_local_type(&name));
+
+        let type_param = match &*node.self_ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => type_path.path.get_ident(),
+            _ => None,
+        };
+        let blanket = type_param
+            .map(|ident| {
+                node.generics
+                    .type_params()
+                    .any(|param| param.ident == *ident)
+            })
+            .unwrap_or(false);
+
+        HelpItem::ItemImpl {
+            trait_: trait_path.map(tokens_to_string),
+            negative: node
+                .trait_
+                .as_ref()
+                .and_then(|(bang, _, _)| bang.as_ref())
+                .is_some(),
+            self_ty: tokens_to_string(&node.self_ty),
+            generic: node.generics.type_params().next().is_some()
+                || node.generics.const_params().next().is_some(),
+            blanket,
+            local_trait,
+            local_type,
+        }
+    }
+    /// Compares the items of the implementation with the ones of the trait, if it's declared in
+    /// the same file
+    fn impl_for_trait_help(&self, node: &syn::ItemImpl, path: &syn::Path) -> HelpItem {
+        let trait_ = tokens_to_string(path);
+        let macros = node
+            .items
+            .iter()
+            .any(|item| std::matches!(item, syn::ImplItem::Macro(..)));
+
+        let item_trait = match self.resolve_trait(path) {
+            Some(item_trait) => item_trait,
+            None => {
+                return HelpItem::ItemImplForTrait {
+                    trait_,
+                    local: false,
+                    missing: vec![],
+                    overridden: vec![],
+                    unknown: vec![],
+                    macros,
+                }
             }
-        ];
+        };
+
+        let trait_items: Vec<_> = item_trait
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::TraitItem::Method(method) => {
+                    Some((&method.sig.ident, method.default.is_some()))
+                }
+                syn::TraitItem::Type(ty) => Some((&ty.ident, ty.default.is_some())),
+                syn::TraitItem::Const(constant) => {
+                    Some((&constant.ident, constant.default.is_some()))
+                }
+                _ => None,
+            })
+            .collect();
+        let impl_items: Vec<_> = node
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Method(method) => Some(&method.sig.ident),
+                syn::ImplItem::Type(ty) => Some(&ty.ident),
+                syn::ImplItem::Const(constant) => Some(&constant.ident),
+                _ => None,
+            })
+            .collect();
+
+        let in_impl = |ident: &syn::Ident| impl_items.contains(&ident);
+        let trait_items_where = |overridden: bool| {
+            trait_items
+                .iter()
+                .filter(|(ident, default)| *default == overridden && in_impl(ident) == overridden)
+                .map(|(ident, _)| ident.to_string())
+                .collect()
+        };
+
+        HelpItem::ItemImplForTrait {
+            trait_,
+            local: true,
+            missing: if macros {
+                vec![]
+            } else {
+                trait_items_where(false)
+            },
+            overridden: trait_items_where(true),
+            unknown: impl_items
+                .iter()
+                .filter(|ident| !trait_items.iter().any(|(item, _)| item == *ident))
+                .map(ToString::to_string)
+                .collect(),
+            macros,
+        }
     }
     pub(super) fn visit_item_macro(&mut self, node: &syn::ItemMacro) {
         if let Some(ident) = &node.ident {
@@ -76,11 +237,18 @@ impl<'a> NodeAnalyzer<'a> {
                 return self.set_help_between(
                     node.mod_token.span(),
                     node.ident.span(),
-                    HelpItem::ItemInlineMod,
+                    HelpItem::ItemInlineMod {
+                        visibility: self.item_visibility(&node.vis, &node.ident),
+                    },
                 );
             }
         } else {
-            return self.set_help(&node, HelpItem::ItemExternMod);
+            return self.set_help(
+                &node,
+                HelpItem::ItemExternMod {
+                    visibility: self.item_visibility(&node.vis, &node.ident),
+                },
+            );
         }
     }
     pub(super) fn visit_item_static(&mut self, node: &syn::ItemStatic) {
@@ -91,13 +259,14 @@ impl<'a> NodeAnalyzer<'a> {
             .unwrap_or_else(|| node.static_token.span());
 
         if self.between_spans(node.static_token.span(), end) {
+            let visibility = self.item_visibility(&node.vis, &node.ident);
             return self.set_help_between(
                 node.static_token.span(),
                 end,
                 if node.mutability.is_some() {
-                    HelpItem::StaticMut
+                    HelpItem::StaticMut { visibility }
                 } else {
-                    HelpItem::Static
+                    HelpItem::Static { visibility }
                 },
             );
         }
@@ -118,6 +287,19 @@ impl<'a> NodeAnalyzer<'a> {
                     unit,
                     name: node.ident.to_string(),
                     generic: self.generics_for(self.id).is_some(),
+                    fields: node
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, field)| match &field.ident {
+                            Some(ident) => ident.to_string(),
+                            None => idx.to_string(),
+                        })
+                        .collect(),
+                    drop_impl: self
+                        .find_trait_impl("Drop", &node.ident.to_string())
+                        .is_some(),
+                    visibility: self.item_visibility(&node.vis, &node.ident),
                 },
             );
         }
@@ -129,7 +311,10 @@ impl<'a> NodeAnalyzer<'a> {
         token![self, some node.unsafety, ItemUnsafeTrait];
         token![self, some node.auto_token, ItemAutoTrait];
         token![self, node.trait_token => node.ident, * HelpItem::ItemTrait {
-            generic: self.generics_for(self.id).is_some()
+            generic: self.generics_for(self.id).is_some(),
+            supertraits: self.supertraits(node),
+            safety: self.object_safety(node),
+            visibility: self.item_visibility(&node.vis, &node.ident),
         } ];
         if let Some(colon_token) = node.colon_token {
             if self.within(colon_token) {
@@ -141,7 +326,10 @@ impl<'a> NodeAnalyzer<'a> {
                 return self.set_help_between(
                     colon_token.span(),
                     last,
-                    HelpItem::ItemTraitSupertraits,
+                    HelpItem::ItemTraitSupertraits {
+                        supertraits: self.supertraits(node),
+                        safety: self.object_safety(node),
+                    },
                 );
             }
         }
@@ -153,7 +341,9 @@ impl<'a> NodeAnalyzer<'a> {
         token![self, node.trait_token, ItemTraitAlias];
     }
     pub(super) fn visit_item_type(&mut self, node: &syn::ItemType) {
-        token![self, node.type_token => node.ident, ItemType];
+        token![self, node.type_token => node.ident, * HelpItem::ItemType {
+            visibility: self.item_visibility(&node.vis, &node.ident),
+        }];
     }
     pub(super) fn visit_item_union_first_pass(&mut self, node: &syn::ItemUnion) {
         fill_generics![self, node];
@@ -163,7 +353,8 @@ impl<'a> NodeAnalyzer<'a> {
             self,
             node.union_token,
             *HelpItem::ItemUnion {
-                generic: self.generics_for(self.id).is_some()
+                generic: self.generics_for(self.id).is_some(),
+                visibility: self.item_visibility(&node.vis, &node.ident),
             }
         ];
     }
@@ -181,3 +372,32 @@ fn vis_span(vis: &syn::Visibility) -> Option<Span> {
         Some(vis.span())
     }
 }
+
+/// The name of the type that counts for the orphan rule: references, `Box` and `Pin` are
+/// _fundamental_, so `&Local` or `Box<Local>` are as local as `Local`
+fn fundamental_type_name(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Paren(syn::TypeParen { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. })
+        | syn::Type::Reference(syn::TypeReference { elem, .. }) => fundamental_type_name(elem),
+        syn::Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            let wrapped = match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments)
+                    if segment.ident == "Box" || segment.ident == "Pin" =>
+                {
+                    arguments.args.iter().find_map(|argument| match argument {
+                        syn::GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            };
+            match wrapped {
+                Some(ty) => fundamental_type_name(ty),
+                None => type_name(ty),
+            }
+        }
+        _ => None,
+    }
+}
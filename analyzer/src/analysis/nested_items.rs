@@ -1,12 +1,48 @@
-use super::{receiver_help, NodeAnalyzer};
+use super::{printing::tokens_to_string, receiver_help, NodeAnalyzer};
 use crate::help::{FnOf, HelpItem};
 use crate::syn_wrappers::Syn;
 use quote::ToTokens;
 use syn::spanned::Spanned;
 
+/// Associated types of well-known std traits, with the module of the trait and their bounds
+const STD_ASSOCIATED_TYPES: &[(&str, &str, &str, &[&str])] = &[
+    ("Iterator", "Item", "iter", &[]),
+    ("IntoIterator", "Item", "iter", &[]),
+    (
+        "IntoIterator",
+        "IntoIter",
+        "iter",
+        &["Iterator<Item = Self::Item>"],
+    ),
+    ("Deref", "Target", "ops", &[]),
+    ("Index", "Output", "ops", &[]),
+    ("Add", "Output", "ops", &[]),
+    ("Sub", "Output", "ops", &[]),
+    ("Mul", "Output", "ops", &[]),
+    ("Div", "Output", "ops", &[]),
+    ("Rem", "Output", "ops", &[]),
+    ("Neg", "Output", "ops", &[]),
+    ("Not", "Output", "ops", &[]),
+    ("FromStr", "Err", "str", &[]),
+    ("TryFrom", "Error", "convert", &[]),
+    ("Future", "Output", "future", &[]),
+];
+
 impl<'a> NodeAnalyzer<'a> {
     pub(super) fn visit_impl_item_const(&mut self, node: &syn::ImplItemConst) {
-        token![self, node.const_token, ImplItemConst];
+        token![self, node.const_token, *self.impl_item_const_help(node)];
+    }
+    fn impl_item_const_help(&self, node: &syn::ImplItemConst) -> HelpItem {
+        let declaration = self.trait_item_declaration(&node.ident);
+        HelpItem::ImplItemConst {
+            name: node.ident.to_string(),
+            trait_: declaration.map(|(item_trait, _)| item_trait.ident.to_string()),
+            ty: declaration.and_then(|(_, item)| match item {
+                syn::TraitItem::Const(constant) => Some(tokens_to_string(&constant.ty)),
+                _ => None,
+            }),
+            line: declaration.map(|(_, item)| declaration_line(item)),
+        }
     }
     pub(super) fn visit_impl_item_method_first_pass(&mut self, node: &syn::ImplItemMethod) {
         self.fill_generics_info(self.id, (&node.sig.generics).into(), false);
@@ -42,7 +78,69 @@ impl<'a> NodeAnalyzer<'a> {
         }
     }
     pub(super) fn visit_impl_item_type(&mut self, node: &syn::ImplItemType) {
-        token![self, node.type_token, ImplItemType];
+        token![self, node.type_token, *self.impl_item_type_help(node)];
+    }
+    fn impl_item_type_help(&self, node: &syn::ImplItemType) -> HelpItem {
+        let name = node.ident.to_string();
+
+        if let Some((item_trait, item)) = self.trait_item_declaration(&node.ident) {
+            let bounds = match item {
+                syn::TraitItem::Type(ty) => ty.bounds.iter().map(tokens_to_string).collect(),
+                _ => vec![],
+            };
+            return HelpItem::ImplItemType {
+                name,
+                trait_: Some(item_trait.ident.to_string()),
+                bounds,
+                line: Some(declaration_line(item)),
+                std_docs: None,
+            };
+        }
+
+        let std_item = self.impl_trait_name().and_then(|trait_| {
+            STD_ASSOCIATED_TYPES
+                .iter()
+                .find(|(std_trait, item, ..)| *std_trait == trait_ && *item == name)
+        });
+        match std_item {
+            Some((trait_, _, module, bounds)) => HelpItem::ImplItemType {
+                trait_: Some(trait_.to_string()),
+                bounds: bounds.iter().map(ToString::to_string).collect(),
+                std_docs: Some(format!(
+                    "{}/trait.{}.html#associatedtype.{}",
+                    module, trait_, name
+                )),
+                name,
+                line: None,
+            },
+            None => HelpItem::ImplItemType {
+                name,
+                trait_: None,
+                bounds: vec![],
+                line: None,
+                std_docs: None,
+            },
+        }
+    }
+    /// The name of the trait implemented by the enclosing `impl` block
+    fn impl_trait_name(&self) -> Option<String> {
+        let (_, path, _) = get_ancestor![self, ItemImpl, 2]?.trait_.as_ref()?;
+        Some(path.segments.last()?.ident.to_string())
+    }
+    /// The item named `ident` in the trait implemented by the enclosing `impl` block, if the
+    /// trait is declared in the same file
+    fn trait_item_declaration(
+        &self,
+        ident: &syn::Ident,
+    ) -> Option<(&'a syn::ItemTrait, &'a syn::TraitItem)> {
+        let (_, path, _) = get_ancestor![self, ItemImpl, 2]?.trait_.as_ref()?;
+        let item_trait = self.resolve_trait(path)?;
+        let item = item_trait.items.iter().find(|item| match item {
+            syn::TraitItem::Type(ty) => ty.ident == *ident,
+            syn::TraitItem::Const(constant) => constant.ident == *ident,
+            _ => false,
+        })?;
+        Some((item_trait, item))
     }
     pub(super) fn visit_trait_item_const(&mut self, node: &syn::TraitItemConst) {
         token![self, node.const_token, TraitItemConst];
@@ -76,3 +174,12 @@ impl<'a> NodeAnalyzer<'a> {
         token![self, node.type_token, TraitItemType];
     }
 }
+
+/// The line of the name of a trait item, which comes after its attributes
+fn declaration_line(item: &syn::TraitItem) -> usize {
+    match item {
+        syn::TraitItem::Type(ty) => ty.ident.span().start().line,
+        syn::TraitItem::Const(constant) => constant.ident.span().start().line,
+        _ => item.span().start().line,
+    }
+}
@@ -1,7 +1,11 @@
-use super::NodeAnalyzer;
+use super::expressions::type_name;
+use super::resolution::{is_capitalized, PRELUDE_VARIANTS};
+use super::{printing::tokens_to_string, receiver_kind, NodeAnalyzer};
 use crate::help::HelpItem;
-use crate::help::{BindingOf, RestOf};
+use crate::help::{AnnotationOf, BindingMode, BindingOf, PathPatOf, RestOf, ScopeOf, SelfKind};
+use crate::ir::Location;
 use crate::syn_wrappers::{Syn, SynKind};
+use std::collections::HashSet;
 use syn::spanned::Spanned;
 
 impl<'a> NodeAnalyzer<'a> {
@@ -10,6 +14,14 @@ impl<'a> NodeAnalyzer<'a> {
     }
     pub(super) fn visit_pat_ident(&mut self, node: &syn::PatIdent) {
         if let Some((at_token, subpat)) = &node.subpat {
+            if let (syn::Pat::Rest(..), Some(..)) = (&**subpat, get_ancestor![self, PatSlice, 2]) {
+                return self.set_help(
+                    node,
+                    HelpItem::PatSliceRest {
+                        ident: Some(node.ident.to_string()),
+                    },
+                );
+            }
             if self.between(at_token, subpat) {
                 return self.set_help(
                     node,
@@ -35,27 +47,103 @@ impl<'a> NodeAnalyzer<'a> {
             }
         }
 
+        // `None`, `MAX`: single segment paths are parsed as identifiers
+        if node.by_ref.is_none()
+            && node.mutability.is_none()
+            && node.subpat.is_none()
+            && is_capitalized(&node.ident)
+        {
+            return self.set_help(
+                node,
+                HelpItem::PatPath {
+                    name: node.ident.to_string(),
+                    of: self.path_pat_of(None, &node.ident),
+                    refutable: self.is_refutable_path(&node.ident.clone().into(), true),
+                },
+            );
+        }
+
         self.visit_simple_pat_ident(node);
+        if self.help.is_some() {
+            return;
+        }
+
+        // Bindings in a larger pattern are explained along with it, and the ones in `let`
+        // statements along with the statement. The end of the name is left to what follows it,
+        // like the `:` of a type annotation
+        if !self.within(&node.ident) || Location::from(node.ident.span().end()) <= self.location {
+            return;
+        }
+        if let Some(scope) = self
+            .binding_scope(node)
+            .filter(|scope| !scope.destructured && !std::matches!(scope.of, ScopeOf::Block))
+        {
+            return self.set_help(
+                &node.ident,
+                HelpItem::PatIdentScope {
+                    ident: node.ident.to_string(),
+                    of: scope.of,
+                    shadowed: scope.shadowed,
+                },
+            );
+        }
     }
     pub(super) fn visit_simple_pat_ident(&mut self, node: &syn::PatIdent) {
         let item = HelpItem::PatIdent {
             mutability: node.mutability.is_some(),
             by_ref: node.by_ref.is_some(),
             ident: node.ident.to_string(),
+            default_binding: None,
         };
         match (node.by_ref, node.mutability) {
             (Some(by_ref), Some(mutability)) => token![self, by_ref => mutability, * item],
             (Some(by_ref), None) => token![self, by_ref, *item],
             (None, Some(mutability)) => token![self, mutability, *item],
-            _ => {}
+            (None, None) => {
+                if let Some(mode) = self.binding_context().mode {
+                    return self.set_help(
+                        node,
+                        HelpItem::PatIdent {
+                            mutability: false,
+                            by_ref: false,
+                            ident: node.ident.to_string(),
+                            default_binding: Some(mode),
+                        },
+                    );
+                }
+            }
         }
     }
+    pub(super) fn visit_pat_lit_first_pass(&mut self, node: &syn::PatLit) {
+        return self.set_help(node, HelpItem::PatLit);
+    }
     pub(super) fn visit_pat_or(&mut self, node: &syn::PatOr) {
         token![self, some node.leading_vert, PatOrLeading];
         for pair in node.cases.pairs() {
             token![self, some pair.punct(), PatOr];
         }
     }
+    pub(super) fn visit_pat_path(&mut self, node: &syn::PatPath) {
+        let mut segments = node
+            .path
+            .segments
+            .iter()
+            .rev()
+            .map(|segment| &segment.ident);
+        let ident = match segments.next() {
+            Some(ident) => ident,
+            None => return,
+        };
+
+        return self.set_help(
+            node,
+            HelpItem::PatPath {
+                name: ident.to_string(),
+                of: self.path_pat_of(segments.next(), ident),
+                refutable: self.is_refutable_path(&node.path, true),
+            },
+        );
+    }
     pub(super) fn visit_pat_range(&mut self, node: &syn::PatRange) {
         return self.set_help(
             node,
@@ -68,11 +156,48 @@ impl<'a> NodeAnalyzer<'a> {
             },
         );
     }
+    pub(super) fn visit_pat_reference(&mut self, node: &syn::PatReference) {
+        return self.set_help(
+            node,
+            HelpItem::PatReference {
+                mutability: node.mutability.is_some(),
+                refutable: self.is_refutable(&node.pat),
+            },
+        );
+    }
+    pub(super) fn visit_pat_slice(&mut self, node: &syn::PatSlice) {
+        for pat in &node.elems {
+            if let syn::Pat::Rest(..) = pat {
+                token![self, pat, *HelpItem::PatSliceRest { ident: None }];
+            }
+        }
+
+        let rest = node.elems.iter().any(|pat| match pat {
+            syn::Pat::Rest(..) => true,
+            syn::Pat::Ident(syn::PatIdent {
+                subpat: Some((_, subpat)),
+                ..
+            }) => std::matches!(&**subpat, syn::Pat::Rest(..)),
+            _ => false,
+        });
+
+        return self.set_help(
+            node,
+            HelpItem::PatSlice {
+                len: node.elems.len() - if rest { 1 } else { 0 },
+                rest,
+                default_binding: self.binding_context().switched_mode(),
+                refutable: node.elems.iter().any(|pat| self.is_refutable(pat)),
+            },
+        );
+    }
     pub(super) fn visit_pat_struct(&mut self, node: &syn::PatStruct) {
         if node.fields.is_empty() {
             token![self, some node.dot2_token, * HelpItem::PatStruct {
                 empty: true,
-                bindings: pattern_bindings(&self)
+                bindings: pattern_bindings(&self),
+                default_binding: None,
+                refutable: self.is_refutable_path(&node.path, false)
             }];
         }
         token![self, some node.dot2_token, * HelpItem::PatRest {
@@ -84,6 +209,12 @@ impl<'a> NodeAnalyzer<'a> {
             HelpItem::PatStruct {
                 empty: false,
                 bindings: pattern_bindings(&self),
+                default_binding: self.binding_context().switched_mode(),
+                refutable: self.is_refutable_path(&node.path, false)
+                    || node
+                        .fields
+                        .iter()
+                        .any(|field| self.is_refutable(&field.pat)),
             },
         );
     }
@@ -102,6 +233,8 @@ impl<'a> NodeAnalyzer<'a> {
             HelpItem::PatTuple {
                 bindings: pattern_bindings(&self),
                 single_comma: node.elems.len() == 1 && node.elems.trailing_punct(),
+                default_binding: self.binding_context().switched_mode(),
+                refutable: node.elems.iter().any(|pat| self.is_refutable(pat)),
             }
         };
         return self.set_help(node, item);
@@ -121,9 +254,31 @@ impl<'a> NodeAnalyzer<'a> {
             node,
             HelpItem::PatTupleStruct {
                 bindings: pattern_bindings(&self),
+                default_binding: self.binding_context().switched_mode(),
+                refutable: self.is_refutable_path(&node.path, false)
+                    || node.pat.elems.iter().any(|pat| self.is_refutable(pat)),
             },
         );
     }
+    pub(super) fn visit_pat_type(&mut self, node: &syn::PatType) {
+        let ident = match &*node.pat {
+            syn::Pat::Ident(pat_ident) if pat_ident.ident == "self" => return,
+            syn::Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+            _ => None,
+        };
+
+        let of = if self.has_ancestor(1, SynKind::FnArg) {
+            AnnotationOf::Arg
+        } else if self.has_ancestor(2, SynKind::ExprClosure) {
+            AnnotationOf::ClosureArg
+        } else if self.has_ancestor(2, SynKind::Local) {
+            AnnotationOf::Let
+        } else {
+            return;
+        };
+
+        token![self, node.colon_token, *HelpItem::PatType { ident, of }];
+    }
     pub(super) fn visit_pat_wild(&mut self, node: &syn::PatWild) {
         let last_arm = match (
             get_ancestor![self, ExprMatch, 3],
@@ -139,6 +294,429 @@ impl<'a> NodeAnalyzer<'a> {
 
         return self.set_help(node, HelpItem::PatWild { last_arm });
     }
+
+    /// Follows the value matched by the enclosing pattern down to the current node, to find out
+    /// if identifiers are implicitly bound by reference ("match ergonomics").
+    ///
+    /// Only references that are visible in the source (`&expr`, a local annotated with a
+    /// reference type, `&self`...) are taken into account.
+    fn binding_context(&self) -> BindingContext {
+        let nodes: Vec<Syn> = self
+            .ancestors
+            .iter()
+            .map(|(_, node)| *node)
+            .chain(self.analyzer.id_to_syn(self.id))
+            .collect();
+
+        for idx in (0..nodes.len().saturating_sub(1)).rev() {
+            let reference = match (nodes[idx], nodes[idx + 1]) {
+                (Syn::Arm(arm), Syn::Pat(pat)) if std::ptr::eq(&arm.pat, pat) => {
+                    match nodes[..idx].last() {
+                        Some(Syn::ExprMatch(expr_match)) => self.reference_of(&expr_match.expr),
+                        _ => None,
+                    }
+                }
+                (Syn::ExprLet(expr_let), Syn::Pat(pat)) if std::ptr::eq(&expr_let.pat, pat) => {
+                    self.reference_of(&expr_let.expr)
+                }
+                // Iterators don't necessarily yield references to the items (e.g. `HashMap::iter`
+                // yields tuples of references), so only `&expr` is taken into account
+                (Syn::ExprForLoop(for_loop), Syn::Pat(pat)) if std::ptr::eq(&for_loop.pat, pat) => {
+                    syntactic_reference(&for_loop.expr)
+                }
+                (Syn::Local(local), Syn::Pat(pat)) if std::ptr::eq(&local.pat, pat) => local
+                    .init
+                    .as_ref()
+                    .and_then(|(_, init)| self.reference_of(init)),
+                // The type annotation is picked up below
+                (Syn::ExprClosure(closure), Syn::Pat(pat))
+                    if closure.inputs.iter().any(|input| std::ptr::eq(input, pat)) =>
+                {
+                    None
+                }
+                (Syn::FnArg(..), Syn::PatType(..)) => None,
+                _ => continue,
+            };
+
+            return nodes[idx + 1..nodes.len() - 1].iter().fold(
+                BindingContext {
+                    mode: None,
+                    reference,
+                },
+                |context, node| context.enter(*node),
+            );
+        }
+
+        BindingContext::default()
+    }
+    /// Whether `expr` evaluates to a reference, as far as we can tell from the source
+    fn reference_of(&self, expr: &syn::Expr) -> Option<BindingMode> {
+        let ident = match expr {
+            syn::Expr::Path(expr_path) => expr_path.path.get_ident()?,
+            _ => return syntactic_reference(expr),
+        };
+
+        if ident == "self" {
+            let sig = self
+                .ancestors
+                .iter()
+                .rev()
+                .find_map(|(_, node)| match node {
+                    Syn::ImplItemMethod(method) => Some(&method.sig),
+                    Syn::TraitItemMethod(method) => Some(&method.sig),
+                    _ => None,
+                })?;
+
+            return match receiver_kind(sig)? {
+                SelfKind::Ref => Some(BindingMode::Ref),
+                SelfKind::RefMut => Some(BindingMode::RefMut),
+                _ => None,
+            };
+        }
+
+        let local = self.find_local(ident)?;
+        match local.ty {
+            Some(syn::Type::Reference(reference)) => Some(binding_mode(&reference.mutability)),
+            _ => local.init.and_then(syntactic_reference),
+        }
+    }
+    /// Points out refutable patterns where only irrefutable ones are allowed. The matched value
+    /// is used to suggest alternatives, like `if let`
+    pub(super) fn visit_irrefutable_pat_first_pass(
+        &mut self,
+        pat: &syn::Pat,
+        of: BindingOf,
+        scrutinee: Option<&syn::Expr>,
+    ) {
+        let pat = match pat {
+            syn::Pat::Type(pat_type) => &*pat_type.pat,
+            pat => pat,
+        };
+
+        if self.within(pat) && self.is_refutable(pat) {
+            return self.set_help(
+                pat,
+                HelpItem::PatRefutable {
+                    of,
+                    pattern: tokens_to_string(pat),
+                    scrutinee: scrutinee.map(tokens_to_string),
+                },
+            );
+        }
+    }
+    /// Whether `pat` may fail to match a value of the expected type.
+    ///
+    /// Types declared outside the file are assumed to be structs, and enums outside the file to
+    /// have more than one variant. Slice patterns are assumed to match arrays of the right length.
+    pub(super) fn is_refutable(&self, pat: &syn::Pat) -> bool {
+        match pat {
+            syn::Pat::Box(pat_box) => self.is_refutable(&pat_box.pat),
+            syn::Pat::Ident(pat_ident) => match &pat_ident.subpat {
+                Some((_, subpat)) => self.is_refutable(subpat),
+                None => self.is_refutable_ident(&pat_ident.ident),
+            },
+            syn::Pat::Lit(..) | syn::Pat::Range(..) => true,
+            syn::Pat::Or(pat_or) => {
+                pat_or.cases.iter().all(|pat| self.is_refutable(pat)) && !self.covers_enum(pat_or)
+            }
+            syn::Pat::Path(pat_path) => self.is_refutable_path(&pat_path.path, true),
+            syn::Pat::Reference(pat_ref) => self.is_refutable(&pat_ref.pat),
+            syn::Pat::Slice(pat_slice) => pat_slice.elems.iter().any(|pat| self.is_refutable(pat)),
+            syn::Pat::Struct(pat_struct) => {
+                self.is_refutable_path(&pat_struct.path, false)
+                    || pat_struct
+                        .fields
+                        .iter()
+                        .any(|field| self.is_refutable(&field.pat))
+            }
+            syn::Pat::Tuple(pat_tuple) => pat_tuple.elems.iter().any(|pat| self.is_refutable(pat)),
+            syn::Pat::TupleStruct(pat_tuple_struct) => {
+                self.is_refutable_path(&pat_tuple_struct.path, false)
+                    || pat_tuple_struct
+                        .pat
+                        .elems
+                        .iter()
+                        .any(|pat| self.is_refutable(pat))
+            }
+            syn::Pat::Type(pat_type) => self.is_refutable(&pat_type.pat),
+            _ => false,
+        }
+    }
+    /// Whether an identifier pattern may fail to match. Unlike paths, identifiers that don't
+    /// resolve to a constant or a variant are bindings, which match anything
+    fn is_refutable_ident(&self, ident: &syn::Ident) -> bool {
+        let name = ident.to_string();
+        if !is_capitalized(ident) || self.find_struct(&name).is_some() {
+            return false;
+        }
+        if self.find_const(&name).is_some() {
+            return true;
+        }
+        if let Some(item) = self.find_variant(&name) {
+            return item.variants.len() > 1;
+        }
+
+        PRELUDE_VARIANTS.iter().any(|(variant, _)| *variant == name)
+    }
+    /// Whether the cases of an or-pattern together match every variant of the enum they match on,
+    /// for enums declared in the file and enums from the prelude
+    fn covers_enum(&self, pat_or: &syn::PatOr) -> bool {
+        let mut covered = HashSet::new();
+        if let Some(item) = pat_or.cases.iter().find_map(|pat| self.matched_enum(pat)) {
+            for pat in &pat_or.cases {
+                if self.arm_coverage(pat, item, &mut covered) {
+                    return true;
+                }
+            }
+            return item
+                .variants
+                .iter()
+                .all(|variant| covered.contains(&variant.ident.to_string()));
+        }
+
+        for pat in &pat_or.cases {
+            let (ident, exhaustive) = match pat {
+                syn::Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => {
+                    (&pat_ident.ident, true)
+                }
+                syn::Pat::Path(pat_path) if pat_path.path.segments.len() == 1 => {
+                    (&pat_path.path.segments[0].ident, true)
+                }
+                syn::Pat::TupleStruct(pat_tuple_struct)
+                    if pat_tuple_struct.path.segments.len() == 1 =>
+                {
+                    (
+                        &pat_tuple_struct.path.segments[0].ident,
+                        !pat_tuple_struct
+                            .pat
+                            .elems
+                            .iter()
+                            .any(|pat| self.is_refutable(pat)),
+                    )
+                }
+                _ => continue,
+            };
+            if exhaustive {
+                covered.insert(ident.to_string());
+            }
+        }
+
+        let enums: HashSet<_> = PRELUDE_VARIANTS
+            .iter()
+            .filter(|(variant, _)| covered.contains(*variant))
+            .map(|(_, item)| item)
+            .collect();
+        enums.into_iter().any(|item| {
+            PRELUDE_VARIANTS
+                .iter()
+                .filter(|(_, other)| other == item)
+                .all(|(variant, _)| covered.contains(*variant))
+        })
+    }
+    /// Whether the struct, variant or constant a pattern path refers to may fail to match.
+    ///
+    /// Unknown `unit` paths (i.e. patterns on their own, not the path of a struct pattern) are
+    /// assumed to be enum variants, consistently with `path_pat_of`.
+    fn is_refutable_path(&self, path: &syn::Path, unit: bool) -> bool {
+        let mut segments = path.segments.iter().rev().map(|segment| &segment.ident);
+        let ident = match segments.next() {
+            Some(ident) if ident != "Self" => ident,
+            _ => return false,
+        };
+
+        let parent = match segments.next() {
+            Some(parent) if parent == "Self" => self.enclosing_self_ty().and_then(type_name),
+            Some(parent) if is_capitalized(parent) => Some(parent.to_string()),
+            _ => None,
+        };
+
+        if let Some(parent) = parent {
+            return self
+                .find_enum(&parent)
+                .map(|item| item.variants.len() > 1)
+                .unwrap_or(true);
+        }
+
+        let name = ident.to_string();
+        if let PathPatOf::Const = self.path_pat_of(None, ident) {
+            return true;
+        }
+        if self.find_struct(&name).is_some() {
+            return false;
+        }
+        if let Some(item) = self.find_variant(&name) {
+            return item.variants.len() > 1;
+        }
+
+        unit || PRELUDE_VARIANTS.iter().any(|(variant, _)| *variant == name)
+    }
+    /// Checks which variants are covered by the arms, if they match on an enum declared in the
+    /// file.
+    ///
+    /// Arms with a guard never count as covering a variant, and neither do variant patterns with
+    /// refutable subpatterns.
+    pub(super) fn match_token_help(&self, node: &syn::ExprMatch) -> HelpItem {
+        let item = node.arms.iter().find_map(|arm| self.matched_enum(&arm.pat));
+
+        let item = match item {
+            Some(item) => item,
+            None => {
+                return HelpItem::ExprMatchToken {
+                    enum_: None,
+                    uncovered: vec![],
+                    catch_all: false,
+                    guard: false,
+                }
+            }
+        };
+
+        let mut covered = HashSet::new();
+        let mut catch_all = false;
+        for arm in node.arms.iter().filter(|arm| arm.guard.is_none()) {
+            catch_all |= self.arm_coverage(&arm.pat, item, &mut covered);
+        }
+
+        HelpItem::ExprMatchToken {
+            enum_: Some(item.ident.to_string()),
+            uncovered: item
+                .variants
+                .iter()
+                .map(|variant| variant.ident.to_string())
+                .filter(|variant| !covered.contains(variant))
+                .collect(),
+            catch_all,
+            guard: node.arms.iter().any(|arm| arm.guard.is_some()),
+        }
+    }
+    /// The enum declared in the file whose variants `pat` matches
+    fn matched_enum(&self, pat: &syn::Pat) -> Option<&'a syn::ItemEnum> {
+        let path = match pat {
+            syn::Pat::Box(pat_box) => return self.matched_enum(&pat_box.pat),
+            syn::Pat::Ident(syn::PatIdent {
+                subpat: Some((_, subpat)),
+                ..
+            }) => return self.matched_enum(subpat),
+            syn::Pat::Ident(pat_ident) if is_capitalized(&pat_ident.ident) => {
+                return self.find_variant(&pat_ident.ident.to_string());
+            }
+            syn::Pat::Or(pat_or) => {
+                return pat_or.cases.iter().find_map(|pat| self.matched_enum(pat))
+            }
+            syn::Pat::Reference(pat_ref) => return self.matched_enum(&pat_ref.pat),
+            syn::Pat::Type(pat_type) => return self.matched_enum(&pat_type.pat),
+            syn::Pat::Path(pat_path) => &pat_path.path,
+            syn::Pat::Struct(pat_struct) => &pat_struct.path,
+            syn::Pat::TupleStruct(pat_tuple_struct) => &pat_tuple_struct.path,
+            _ => return None,
+        };
+
+        let mut segments = path.segments.iter().rev().map(|segment| &segment.ident);
+        let variant = segments.next()?.to_string();
+        let item = match segments.next() {
+            Some(parent) if parent == "Self" => {
+                self.find_enum(&type_name(self.enclosing_self_ty()?)?)
+            }
+            Some(parent) => self.find_enum(&parent.to_string()),
+            None => self.find_variant(&variant),
+        }?;
+
+        Some(item).filter(|item| item.variants.iter().any(|v| v.ident == variant))
+    }
+    /// Adds the variants of `item` fully matched by `pat` to `covered`, returns whether `pat`
+    /// matches anything
+    fn arm_coverage<'p>(
+        &self,
+        pat: &'p syn::Pat,
+        item: &syn::ItemEnum,
+        covered: &mut HashSet<String>,
+    ) -> bool {
+        let last_ident = |path: &'p syn::Path| path.segments.last().map(|s| &s.ident);
+        let (variant, exhaustive) = match pat {
+            syn::Pat::Box(pat_box) => return self.arm_coverage(&pat_box.pat, item, covered),
+            syn::Pat::Ident(syn::PatIdent {
+                subpat: Some((_, subpat)),
+                ..
+            }) => return self.arm_coverage(subpat, item, covered),
+            syn::Pat::Ident(pat_ident) if !is_capitalized(&pat_ident.ident) => return true,
+            syn::Pat::Ident(pat_ident) => (Some(&pat_ident.ident), true),
+            syn::Pat::Or(pat_or) => {
+                // Every case must be visited, to collect all the variants
+                return pat_or
+                    .cases
+                    .iter()
+                    .filter(|pat| self.arm_coverage(pat, item, covered))
+                    .count()
+                    > 0;
+            }
+            syn::Pat::Reference(pat_ref) => return self.arm_coverage(&pat_ref.pat, item, covered),
+            syn::Pat::Type(pat_type) => return self.arm_coverage(&pat_type.pat, item, covered),
+            syn::Pat::Wild(..) => return true,
+            syn::Pat::Path(pat_path) => (last_ident(&pat_path.path), true),
+            syn::Pat::Struct(pat_struct) => (
+                last_ident(&pat_struct.path),
+                !pat_struct
+                    .fields
+                    .iter()
+                    .any(|field| self.is_refutable(&field.pat)),
+            ),
+            syn::Pat::TupleStruct(pat_tuple_struct) => (
+                last_ident(&pat_tuple_struct.path),
+                !pat_tuple_struct
+                    .pat
+                    .elems
+                    .iter()
+                    .any(|pat| self.is_refutable(pat)),
+            ),
+            _ => return false,
+        };
+
+        let same_enum = self
+            .matched_enum(pat)
+            .map(|matched| std::ptr::eq(matched, item))
+            .unwrap_or(false);
+
+        if let (Some(variant), true, true) = (variant, exhaustive, same_enum) {
+            covered.insert(variant.to_string());
+        }
+
+        false
+    }
+    /// What a path pattern refers to, based on its last two segments
+    ///
+    /// Variants of the enums declared in the file are resolved first, the naming conventions are
+    /// only used for unknown paths
+    fn path_pat_of(&self, parent: Option<&syn::Ident>, ident: &syn::Ident) -> PathPatOf {
+        let name = ident.to_string();
+        let item = match parent {
+            Some(parent) if parent == "Self" => self
+                .enclosing_self_ty()
+                .and_then(type_name)
+                .and_then(|ty| self.find_enum(&ty)),
+            Some(parent) => self.find_enum(&parent.to_string()),
+            None => self.find_imported_variant(&name),
+        };
+        if item
+            .map(|item| item.variants.iter().any(|variant| variant.ident == *ident))
+            .unwrap_or(false)
+        {
+            return PathPatOf::UnitVariant;
+        }
+
+        let screaming_case = name.len() > 1
+            && name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+
+        if screaming_case || self.find_const(&name).is_some() {
+            PathPatOf::Const
+        } else if parent.map(is_capitalized).unwrap_or(false) {
+            PathPatOf::UnitVariant
+        } else if self.find_struct(&name).is_some() {
+            PathPatOf::UnitStruct
+        } else {
+            PathPatOf::UnitVariant
+        }
+    }
 }
 
 fn pattern_bindings(analyzer: &NodeAnalyzer) -> Option<BindingOf> {
@@ -157,3 +735,64 @@ fn pattern_bindings(analyzer: &NodeAnalyzer) -> Option<BindingOf> {
 
     None
 }
+
+/// The state of the matched value at a pattern node
+#[derive(Clone, Copy, Default)]
+struct BindingContext {
+    /// The default binding mode, `None` meaning bindings move (or copy) the value
+    mode: Option<BindingMode>,
+    /// Whether the value matched at this node is a reference
+    reference: Option<BindingMode>,
+}
+
+impl BindingContext {
+    /// The context for the subpatterns of `node`
+    fn enter(self, node: Syn) -> Self {
+        match node {
+            Syn::PatSlice(..)
+            | Syn::PatStruct(..)
+            | Syn::PatTuple(..)
+            | Syn::PatTupleStruct(..) => BindingContext {
+                mode: self.switched_mode().or(self.mode),
+                reference: None,
+            },
+            Syn::PatType(syn::PatType { ty, .. }) => match &**ty {
+                syn::Type::Reference(reference) if self.reference.is_none() => BindingContext {
+                    reference: Some(binding_mode(&reference.mutability)),
+                    ..self
+                },
+                _ => self,
+            },
+            Syn::PatBox(..) => BindingContext {
+                reference: None,
+                ..self
+            },
+            // Reference patterns reset the binding mode to move
+            Syn::PatReference(..) => BindingContext::default(),
+            _ => self,
+        }
+    }
+    /// The binding mode a non-reference pattern switches to, if it matches a reference
+    fn switched_mode(&self) -> Option<BindingMode> {
+        match (self.mode, self.reference?) {
+            (Some(BindingMode::Ref), _) => Some(BindingMode::Ref),
+            (_, reference) => Some(reference),
+        }
+    }
+}
+
+fn syntactic_reference(expr: &syn::Expr) -> Option<BindingMode> {
+    match expr {
+        syn::Expr::Reference(reference) => Some(binding_mode(&reference.mutability)),
+        syn::Expr::Paren(paren) => syntactic_reference(&paren.expr),
+        _ => None,
+    }
+}
+
+fn binding_mode(mutability: &Option<syn::token::Mut>) -> BindingMode {
+    if mutability.is_some() {
+        BindingMode::RefMut
+    } else {
+        BindingMode::Ref
+    }
+}
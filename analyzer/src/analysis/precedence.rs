@@ -0,0 +1,158 @@
+use super::{printing::tokens_to_string, NodeAnalyzer};
+use crate::{ir::Range, syn_wrappers::Syn};
+use syn::spanned::Spanned;
+
+impl<'a> NodeAnalyzer<'a> {
+    /// The binary expression of the operator at the current location
+    fn operator_expr(&self) -> Option<&'a syn::ExprBinary> {
+        match self.ancestors.last()?.1 {
+            Syn::ExprBinary(expr_binary) => Some(expr_binary),
+            _ => None,
+        }
+    }
+
+    /// Renders the chain of operators around the current one with explicit parentheses, e.g.
+    /// `a + (b * (c as u32))`, and tells whether the operator chains comparisons
+    pub(super) fn operator_grouping(&self) -> (Option<String>, bool) {
+        let expr_binary = match self.operator_expr() {
+            Some(expr_binary) => expr_binary,
+            None => return (None, false),
+        };
+
+        // Either operand, or the enclosing expression, is another comparison
+        let parent = self
+            .ancestors
+            .iter()
+            .rev()
+            .skip(1)
+            .map(|(_, node)| *node)
+            .find(|node| !std::matches!(node, Syn::Expr(..)));
+        let chained = is_comparison(&expr_binary.op)
+            && ([&*expr_binary.left, &*expr_binary.right]
+                .iter()
+                .any(|operand| match operand {
+                    syn::Expr::Binary(operand) => is_comparison(&operand.op),
+                    _ => false,
+                })
+                || match parent {
+                    Some(Syn::ExprBinary(parent)) => is_comparison(&parent.op),
+                    _ => false,
+                });
+
+        // Operators in parentheses are already grouped explicitly, so the chain stops there
+        let top = self
+            .ancestors
+            .iter()
+            .rev()
+            .map(|(_, node)| *node)
+            .take_while(|node| {
+                std::matches!(
+                    node,
+                    Syn::Expr(..) | Syn::ExprBinary(..) | Syn::ExprUnary(..) | Syn::ExprCast(..)
+                )
+            })
+            .filter_map(|node| match node {
+                Syn::ExprBinary(expr_binary) => Some(Operation::Binary(expr_binary)),
+                Syn::ExprUnary(expr_unary) => Some(Operation::Unary(expr_unary)),
+                Syn::ExprCast(expr_cast) => Some(Operation::Cast(expr_cast)),
+                _ => None,
+            })
+            .last();
+
+        let grouping = match top {
+            Some(Operation::Binary(top))
+                if !is_operation(&top.left) && !is_operation(&top.right) =>
+            {
+                None
+            }
+            Some(top) => Some(top.render()),
+            None => None,
+        };
+
+        (grouping, chained)
+    }
+
+    /// The operands of the binary operator at the current location, to be highlighted along
+    /// with its explanation
+    pub(super) fn operands(&self) -> Option<Vec<Range>> {
+        let expr_binary = self.operator_expr()?;
+
+        Some(
+            [&*expr_binary.left, &*expr_binary.right]
+                .iter()
+                .map(|operand| {
+                    let span = operand.span();
+                    (span.start().into(), span.end().into())
+                })
+                .collect(),
+        )
+    }
+}
+
+enum Operation<'a> {
+    Binary(&'a syn::ExprBinary),
+    Unary(&'a syn::ExprUnary),
+    Cast(&'a syn::ExprCast),
+}
+
+impl<'a> Operation<'a> {
+    fn from_expr(expr: &'a syn::Expr) -> Option<Self> {
+        match expr {
+            syn::Expr::Binary(expr_binary) => Some(Operation::Binary(expr_binary)),
+            syn::Expr::Unary(expr_unary) => Some(Operation::Unary(expr_unary)),
+            syn::Expr::Cast(expr_cast) => Some(Operation::Cast(expr_cast)),
+            _ => None,
+        }
+    }
+
+    fn render(&self) -> String {
+        match self {
+            Operation::Binary(expr_binary) => format!(
+                "{} {} {}",
+                render_operand(&expr_binary.left),
+                tokens_to_string(&expr_binary.op),
+                render_operand(&expr_binary.right)
+            ),
+            Operation::Unary(expr_unary) => format!(
+                "{}{}",
+                tokens_to_string(&expr_unary.op),
+                render_prefixed(&expr_unary.expr)
+            ),
+            Operation::Cast(expr_cast) => format!(
+                "{} as {}",
+                render_prefixed(&expr_cast.expr),
+                tokens_to_string(&expr_cast.ty)
+            ),
+        }
+    }
+}
+
+fn render_operand(expr: &syn::Expr) -> String {
+    match Operation::from_expr(expr) {
+        Some(operation) => format!("({})", operation.render()),
+        None => tokens_to_string(expr),
+    }
+}
+
+/// Renders the operand of a unary operator or a cast, which binds looser than method calls, field
+/// accesses and other postfix expressions, e.g. `-(c.d)`
+fn render_prefixed(expr: &syn::Expr) -> String {
+    match expr {
+        syn::Expr::Await(..)
+        | syn::Expr::Call(..)
+        | syn::Expr::Field(..)
+        | syn::Expr::Index(..)
+        | syn::Expr::MethodCall(..)
+        | syn::Expr::Try(..) => format!("({})", tokens_to_string(expr)),
+        _ => render_operand(expr),
+    }
+}
+
+fn is_operation(expr: &syn::Expr) -> bool {
+    Operation::from_expr(expr).is_some()
+}
+
+fn is_comparison(op: &syn::BinOp) -> bool {
+    use syn::BinOp::*;
+    std::matches!(op, Eq(..) | Lt(..) | Le(..) | Ne(..) | Ge(..) | Gt(..))
+}
@@ -0,0 +1,298 @@
+//! Printing of syntax nodes as Rust code, for snippets shown in explanations.
+//!
+//! Tokens that come from the user's source keep their original spacing. Tokens generated by the
+//! analyzer have no location, so they are spaced following the usual formatting conventions.
+
+use proc_macro2::{Delimiter, LineColumn, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// Prints `tokens` in a single line, e.g. `&'a str` instead of `& 'a str`
+pub(super) fn tokens_to_string(tokens: &impl ToTokens) -> String {
+    Printer::new(false).print(tokens.to_token_stream())
+}
+
+/// Prints `tokens` as a snippet, with the contents of braces on indented lines
+pub(super) fn tokens_to_snippet(tokens: &impl ToTokens) -> String {
+    Printer::new(true).print(tokens.to_token_stream())
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "box", "break", "const", "continue", "dyn", "else", "enum", "extern", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "type", "unsafe", "use", "where", "while", "yield",
+];
+
+#[derive(Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Punct(char, Spacing),
+    Literal(String),
+    Open(Delimiter),
+    Close(Delimiter),
+}
+
+struct Located {
+    token: Token,
+    /// Only available for tokens that come from the source
+    location: Option<(LineColumn, LineColumn)>,
+}
+
+struct Printer {
+    multiline: bool,
+    out: String,
+    indent: usize,
+    /// Open delimiters, and whether their contents are printed in separate lines
+    delimiters: Vec<(Delimiter, bool)>,
+    generics: usize,
+}
+
+impl Printer {
+    fn new(multiline: bool) -> Self {
+        Printer {
+            multiline,
+            out: String::new(),
+            indent: 0,
+            delimiters: vec![],
+            generics: 0,
+        }
+    }
+
+    fn print(mut self, stream: TokenStream) -> String {
+        let mut tokens = vec![];
+        flatten(stream, &mut tokens);
+
+        for idx in 0..tokens.len() {
+            let (before, rest) = tokens.split_at(idx);
+            let current = &rest[0];
+
+            let closes_broken = match current.token {
+                Token::Close(..) => self.delimiters.pop().map(|(_, broken)| broken),
+                _ => None,
+            };
+
+            if closes_broken == Some(true) {
+                self.indent -= 1;
+                if before[idx - 1].token != Token::Open(Delimiter::Brace) {
+                    self.newline();
+                }
+            } else if idx > 0 {
+                if self.starts_line(before, current) {
+                    self.newline();
+                } else if self.spaced(before, current) {
+                    self.out.push(' ');
+                }
+            }
+
+            match &current.token {
+                Token::Ident(ident) => self.out.push_str(ident),
+                Token::Literal(literal) => self.out.push_str(literal),
+                Token::Punct(c, _) => {
+                    self.out.push(*c);
+                    match c {
+                        '<' if is_generic_open(before, current) => self.generics += 1,
+                        '>' if is_generic_close(before, current, self.generics) => {
+                            self.generics -= 1
+                        }
+                        _ => {}
+                    }
+                }
+                Token::Open(delimiter) => {
+                    self.out.push_str(match delimiter {
+                        Delimiter::Parenthesis => "(",
+                        Delimiter::Brace => "{",
+                        Delimiter::Bracket => "[",
+                        Delimiter::None => "",
+                    });
+                    let broken = self.multiline
+                        && *delimiter == Delimiter::Brace
+                        && !is_short_struct(before, &rest[1..]);
+                    self.delimiters.push((*delimiter, broken));
+                    if broken {
+                        self.indent += 1;
+                    }
+                }
+                Token::Close(delimiter) => self.out.push_str(match delimiter {
+                    Delimiter::Parenthesis => ")",
+                    Delimiter::Brace => "}",
+                    Delimiter::Bracket => "]",
+                    Delimiter::None => "",
+                }),
+            }
+        }
+
+        self.out
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        self.out.push_str(&"    ".repeat(self.indent));
+    }
+
+    /// Statements, match arms and fields start a new line when printed directly inside braces
+    fn starts_line(&self, before: &[Located], current: &Located) -> bool {
+        if !std::matches!(self.delimiters.last(), Some((_, true))) {
+            return false;
+        }
+
+        match (&before[before.len() - 1].token, &current.token) {
+            (Token::Open(Delimiter::Brace), _) => true,
+            (Token::Punct(';', _), _) | (Token::Punct(',', _), _) => true,
+            (Token::Close(Delimiter::Brace), Token::Punct(c, _)) => !",;.?".contains(*c),
+            (Token::Close(Delimiter::Brace), Token::Ident(ident)) => ident != "else",
+            (Token::Close(Delimiter::Brace), _) => true,
+            _ => false,
+        }
+    }
+
+    fn spaced(&self, before: &[Located], current: &Located) -> bool {
+        let previous = &before[before.len() - 1];
+
+        // Tokens that were moved around, like the lifetimes in elided signatures, are not adjacent
+        if let (Some((_, end)), Some((start, _))) = (previous.location, current.location) {
+            if (end.line, end.column) <= (start.line, start.column) {
+                return end != start;
+            }
+        }
+
+        match (&previous.token, &current.token) {
+            (Token::Open(Delimiter::Brace), Token::Close(..)) => false,
+            (Token::Open(Delimiter::Brace), _) | (_, Token::Close(Delimiter::Brace)) => true,
+            (Token::Open(..), _) | (_, Token::Close(..)) => false,
+            (_, Token::Punct(c, _)) if ",;.?:".contains(*c) => false,
+            (Token::Punct('.', _), _) | (Token::Punct('#', _), _) | (Token::Punct('!', _), _) => {
+                false
+            }
+            (Token::Punct(_, Spacing::Joint), _) => false,
+            // Second half of `::` or `..=`
+            (Token::Punct(':', _), _) | (Token::Punct('=', _), _)
+                if before.len() > 1 && is_joint(&before[before.len() - 2].token) =>
+            {
+                !std::matches!(
+                    before[before.len() - 2].token,
+                    Token::Punct(':', _) | Token::Punct('.', _)
+                )
+            }
+            // Lifetimes aren't operands, e.g. `&'a [u8]`
+            (Token::Ident(..), _) if is_lifetime(before) => {
+                !std::matches!(current.token, Token::Punct('>', _))
+            }
+            (Token::Ident(..), Token::Punct('!', _)) => false,
+            (Token::Punct('&', _), _) | (Token::Punct('*', _), _) | (Token::Punct('-', _), _)
+                if !is_operand(&before[..before.len() - 1]) =>
+            {
+                false
+            }
+            (_, Token::Open(Delimiter::Parenthesis)) | (_, Token::Open(Delimiter::Bracket)) => {
+                !is_operand(before)
+            }
+            (Token::Punct('<', _), _) => !is_generic_open(&before[..before.len() - 1], previous),
+            (_, Token::Punct('<', _)) if is_generic_open(before, current) => {
+                !std::matches!(previous.token, Token::Ident(..))
+            }
+            (_, Token::Punct('>', _)) => !is_generic_close(before, current, self.generics),
+            _ => true,
+        }
+    }
+}
+
+fn flatten(stream: TokenStream, tokens: &mut Vec<Located>) {
+    for tree in stream {
+        let span = tree.span();
+        let location = Some((span.start(), span.end())).filter(|(start, end)| start != end);
+
+        match tree {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                flatten(group.stream(), tokens)
+            }
+            TokenTree::Group(group) => {
+                let open = group.span_open();
+                let close = group.span_close();
+                tokens.push(Located {
+                    token: Token::Open(group.delimiter()),
+                    location: location.map(|_| (open.start(), open.end())),
+                });
+                flatten(group.stream(), tokens);
+                tokens.push(Located {
+                    token: Token::Close(group.delimiter()),
+                    location: location.map(|_| (close.start(), close.end())),
+                });
+            }
+            TokenTree::Ident(ident) => tokens.push(Located {
+                token: Token::Ident(ident.to_string()),
+                location,
+            }),
+            TokenTree::Punct(punct) => tokens.push(Located {
+                token: Token::Punct(punct.as_char(), punct.spacing()),
+                location,
+            }),
+            TokenTree::Literal(literal) => tokens.push(Located {
+                token: Token::Literal(literal.to_string()),
+                location,
+            }),
+        }
+    }
+}
+
+/// A struct expression with a single field, like `RangeTo { end: n }`, which fits in one line
+fn is_short_struct(before: &[Located], contents: &[Located]) -> bool {
+    let is_path = std::matches!(
+        before.last().map(|located| &located.token),
+        Some(Token::Ident(ident)) if !KEYWORDS.contains(&ident.as_str())
+    );
+
+    let mut depth = 0;
+    for located in contents {
+        match &located.token {
+            Token::Open(Delimiter::Brace) => return false,
+            Token::Open(..) => depth += 1,
+            Token::Close(..) if depth == 0 => break,
+            Token::Close(..) => depth -= 1,
+            Token::Punct(',', _) | Token::Punct(';', _) if depth == 0 => return false,
+            _ => {}
+        }
+    }
+
+    is_path
+}
+
+fn is_joint(token: &Token) -> bool {
+    matches!(token, Token::Punct(_, Spacing::Joint))
+}
+
+/// Whether the last token ends an operand, e.g. the callee in a function call
+fn is_operand(before: &[Located]) -> bool {
+    match before.last().map(|located| &located.token) {
+        Some(Token::Ident(ident)) => !KEYWORDS.contains(&ident.as_str()),
+        Some(Token::Literal(..)) | Some(Token::Close(..)) => true,
+        Some(Token::Punct('>', _)) => !before[..before.len() - 1]
+            .last()
+            .map(|located| is_joint(&located.token))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Whether the last token ends a lifetime, like `'a`
+fn is_lifetime(before: &[Located]) -> bool {
+    before.len() > 1
+        && std::matches!(before[before.len() - 1].token, Token::Ident(..))
+        && std::matches!(before[before.len() - 2].token, Token::Punct('\'', _))
+}
+
+/// Comparisons are only found in the user's code, which keeps its own spacing
+fn is_generic_open(before: &[Located], current: &Located) -> bool {
+    std::matches!(current.token, Token::Punct('<', Spacing::Alone))
+        && !before
+            .last()
+            .map(|located| is_joint(&located.token))
+            .unwrap_or(false)
+}
+
+fn is_generic_close(before: &[Located], current: &Located, generics: usize) -> bool {
+    generics > 0
+        && std::matches!(current.token, Token::Punct('>', _))
+        && !before
+            .last()
+            .map(|located| is_joint(&located.token))
+            .unwrap_or(false)
+}
@@ -0,0 +1,322 @@
+use super::{
+    resolution::{item_ident, item_node, item_vis},
+    visibility::{render, scope, ModulePath, Scope},
+    NodeAnalyzer,
+};
+use crate::{
+    help::{HelpItem, PrivacyViolationOf},
+    syn_wrappers::Syn,
+};
+
+impl<'a> NodeAnalyzer<'a> {
+    /// The first segment of `path` that names an item which is not visible from the current
+    /// module. Only paths that go through inline modules declared in the file are checked
+    pub(super) fn path_privacy_violation(
+        &self,
+        path: &'a syn::Path,
+    ) -> Option<(&'a syn::Ident, HelpItem)> {
+        let from = self.current_module();
+        let segments: Vec<_> = path.segments.iter().map(|segment| &segment.ident).collect();
+        if segments.len() < 2 {
+            return None;
+        }
+
+        let mut module = from.clone();
+        let mut start = 0;
+        match segments[0].to_string().as_str() {
+            "crate" => {
+                module.clear();
+                start = 1;
+            }
+            "self" => start = 1,
+            "super" => {
+                while segments
+                    .get(start)
+                    .map(|ident| *ident == "super")
+                    .unwrap_or(false)
+                {
+                    module.pop()?;
+                    start += 1;
+                }
+            }
+            // Relative paths are only followed if they start with a module
+            name => {
+                if !std::matches!(self.module_item(&module, name), Some(syn::Item::Mod(..))) {
+                    return None;
+                }
+            }
+        }
+
+        for ident in &segments[start..] {
+            let item = self.module_item(&module, &ident.to_string())?;
+            let item_scope = scope(item_vis(item_node(item)?)?, &module);
+            if !visible(&item_scope, &from) {
+                return Some((
+                    ident,
+                    HelpItem::PrivacyViolation {
+                        of: PrivacyViolationOf::Item,
+                        name: ident.to_string(),
+                        declared_in: render(&module),
+                        visible_in: item_scope.as_ref().map(|scope| render(scope))?,
+                        from: render(&from),
+                        fields: vec![],
+                    },
+                ));
+            }
+
+            match item {
+                syn::Item::Mod(..) => module.push(ident.to_string()),
+                _ => return None,
+            }
+        }
+
+        None
+    }
+
+    /// Checks whether the field `name` of the struct named by `path` is visible here
+    pub(super) fn field_privacy_violation(
+        &self,
+        path: &syn::Path,
+        name: &syn::Ident,
+    ) -> Option<HelpItem> {
+        let (module, item_struct) = self.resolve_struct(path)?;
+        let field = item_struct
+            .fields
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(name))?;
+        self.field_violation(&module, item_struct, field)
+    }
+
+    /// Checks the visibility of a field accessed with `.`. Without type information, the field
+    /// is only reported if every struct in the file with a field of that name hides it
+    pub(super) fn field_access_privacy_violation(&self, name: &syn::Ident) -> Option<HelpItem> {
+        let violations: Vec<_> = self
+            .structs()
+            .into_iter()
+            .filter_map(|(module, item_struct)| {
+                let field = item_struct
+                    .fields
+                    .iter()
+                    .find(|field| field.ident.as_ref() == Some(name))?;
+                Some(self.field_violation(&module, item_struct, field))
+            })
+            .collect::<Option<_>>()?;
+
+        violations.into_iter().next()
+    }
+
+    /// Checks whether the struct named by `path` can be built with a struct expression here,
+    /// which requires every field to be visible
+    pub(super) fn constructor_privacy_violation(&self, path: &syn::Path) -> Option<HelpItem> {
+        let from = self.current_module();
+        let (module, item_struct) = self.resolve_struct(path)?;
+        let fields: Vec<_> = item_struct
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| !visible(&scope(&field.vis, &module), &from))
+            .map(|(index, field)| match &field.ident {
+                Some(ident) => ident.to_string(),
+                None => index.to_string(),
+            })
+            .collect();
+        if fields.is_empty() {
+            return None;
+        }
+
+        Some(HelpItem::PrivacyViolation {
+            of: PrivacyViolationOf::Constructor,
+            name: item_struct.ident.to_string(),
+            declared_in: render(&module),
+            visible_in: render(&module),
+            from: render(&from),
+            fields,
+        })
+    }
+
+    fn field_violation(
+        &self,
+        module: &[String],
+        item_struct: &syn::ItemStruct,
+        field: &syn::Field,
+    ) -> Option<HelpItem> {
+        let from = self.current_module();
+        let field_scope = scope(&field.vis, module);
+        if visible(&field_scope, &from) {
+            return None;
+        }
+
+        Some(HelpItem::PrivacyViolation {
+            of: PrivacyViolationOf::Field,
+            name: field.ident.as_ref()?.to_string(),
+            declared_in: render(&[module, &[item_struct.ident.to_string()]].concat()),
+            visible_in: field_scope.as_ref().map(|scope| render(scope))?,
+            from: render(&from),
+            fields: vec![],
+        })
+    }
+
+    /// The struct named by `path`, and the module it's declared in. Single identifiers are
+    /// resolved in the current module, or through the `use` declarations of the current module
+    /// and the enclosing blocks. Items declared in an enclosing block shadow both, and are always
+    /// visible from within it
+    fn resolve_struct(&self, path: &syn::Path) -> Option<(ModulePath, &'a syn::ItemStruct)> {
+        let segments: Vec<_> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        let (name, prefix) = segments.split_last()?;
+
+        let (module, name) = if prefix.is_empty() {
+            if self.block_items().any(|item| is_named(item, name)) {
+                return None;
+            }
+            match self.module_item(&self.current_module(), name) {
+                Some(..) => (self.current_module(), name.clone()),
+                None => self.resolve_import(name)?,
+            }
+        } else {
+            (self.resolve_module(prefix)?, name.clone())
+        };
+
+        match self.module_item(&module, &name)? {
+            syn::Item::Struct(item_struct) => Some((module, item_struct)),
+            _ => None,
+        }
+    }
+
+    /// The module named by the segments of a path, relative to the current module
+    fn resolve_module(&self, segments: &[String]) -> Option<ModulePath> {
+        let mut module = self.current_module();
+        for segment in segments {
+            match segment.as_str() {
+                "crate" => module.clear(),
+                "self" => {}
+                "super" => {
+                    module.pop()?;
+                }
+                name => module.push(name.to_string()),
+            }
+        }
+        Some(module)
+    }
+
+    /// The module and the original name of the item imported as `name`, by a `use` declaration
+    /// of the current module or of an enclosing block
+    fn resolve_import(&self, name: &str) -> Option<(ModulePath, String)> {
+        let module_items = self.module_items(&self.current_module()).unwrap_or(&[]);
+        self.block_items()
+            .chain(module_items)
+            .filter_map(|item| match item {
+                syn::Item::Use(item_use) => Some(&item_use.tree),
+                _ => None,
+            })
+            .find_map(|tree| self.resolve_use_tree(tree, &mut vec![], name))
+    }
+
+    fn resolve_use_tree(
+        &self,
+        tree: &syn::UseTree,
+        prefix: &mut Vec<String>,
+        name: &str,
+    ) -> Option<(ModulePath, String)> {
+        match tree {
+            syn::UseTree::Path(use_path) => {
+                prefix.push(use_path.ident.to_string());
+                let resolved = self.resolve_use_tree(&use_path.tree, prefix, name);
+                prefix.pop();
+                resolved
+            }
+            syn::UseTree::Name(use_name) if use_name.ident == name => {
+                Some((self.resolve_module(prefix)?, name.to_string()))
+            }
+            syn::UseTree::Rename(use_rename) if use_rename.rename == name => {
+                Some((self.resolve_module(prefix)?, use_rename.ident.to_string()))
+            }
+            syn::UseTree::Glob(..) => {
+                let module = self.resolve_module(prefix)?;
+                self.module_item(&module, name)?;
+                Some((module, name.to_string()))
+            }
+            syn::UseTree::Group(use_group) => use_group
+                .items
+                .iter()
+                .find_map(|tree| self.resolve_use_tree(tree, prefix, name)),
+            _ => None,
+        }
+    }
+
+    /// The items declared in the blocks that enclose the current location
+    fn block_items(&self) -> impl Iterator<Item = &'a syn::Item> + '_ {
+        self.ancestors
+            .iter()
+            .filter_map(|(_, node)| match node {
+                Syn::Block(block) => Some(&block.stmts),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|stmt| match stmt {
+                syn::Stmt::Item(item) => Some(item),
+                _ => None,
+            })
+    }
+
+    /// The items declared directly in the inline module `module`
+    fn module_items(&self, module: &[String]) -> Option<&'a [syn::Item]> {
+        let mut items = self.nodes().find_map(|node| match node {
+            Syn::File(file) => Some(&file.items[..]),
+            _ => None,
+        })?;
+        for name in module {
+            items = items.iter().find_map(|item| match item {
+                syn::Item::Mod(item_mod) if item_mod.ident == name => {
+                    item_mod.content.as_ref().map(|(_, items)| &items[..])
+                }
+                _ => None,
+            })?;
+        }
+        Some(items)
+    }
+
+    fn module_item(&self, module: &[String], name: &str) -> Option<&'a syn::Item> {
+        self.module_items(module)?
+            .iter()
+            .find(|item| is_named(item, name))
+    }
+
+    /// Every struct declared in the file outside of function bodies, with its module
+    fn structs(&self) -> Vec<(ModulePath, &'a syn::ItemStruct)> {
+        let mut structs = vec![];
+        let mut pending = vec![vec![]];
+
+        while let Some(module) = pending.pop() {
+            for item in self.module_items(&module).into_iter().flatten() {
+                match item {
+                    syn::Item::Struct(item_struct) => structs.push((module.clone(), item_struct)),
+                    syn::Item::Mod(item_mod) if item_mod.content.is_some() => {
+                        pending.push([&module[..], &[item_mod.ident.to_string()]].concat())
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        structs
+    }
+}
+
+/// Whether something visible within `scope` can be named from the module `from`
+fn visible(scope: &Scope, from: &[String]) -> bool {
+    match scope {
+        Some(scope) => from.starts_with(scope),
+        None => true,
+    }
+}
+
+fn is_named(item: &syn::Item, name: &str) -> bool {
+    item_node(item)
+        .and_then(item_ident)
+        .map(|ident| ident == name)
+        .unwrap_or(false)
+}
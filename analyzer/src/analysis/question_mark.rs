@@ -0,0 +1,156 @@
+use super::{printing::tokens_to_string, NodeAnalyzer};
+use crate::{
+    help::{HelpItem, ReturnOf, TryReturnType},
+    syn_wrappers::Syn,
+};
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Explains `?` according to the return type of the function, closure or block it returns
+    /// from
+    pub(super) fn question_mark_help(&self) -> HelpItem {
+        let mut enclosing = self.ancestors.iter().rev().map(|(_, node)| *node);
+
+        let (of, target, main) = loop {
+            match enclosing.next() {
+                Some(Syn::ItemFn(item_fn)) => {
+                    break (
+                        ReturnOf::Function,
+                        fn_output(&item_fn.sig.output),
+                        item_fn.sig.ident == "main",
+                    )
+                }
+                Some(Syn::ImplItemMethod(syn::ImplItemMethod { sig, .. }))
+                | Some(Syn::TraitItemMethod(syn::TraitItemMethod { sig, .. })) => {
+                    break (ReturnOf::Method, fn_output(&sig.output), false)
+                }
+                // Closures without an annotation have an inferred return type
+                Some(Syn::ExprClosure(closure)) => {
+                    let target = match &closure.output {
+                        syn::ReturnType::Type(_, ty) => Some(TryTarget::Type(ty)),
+                        syn::ReturnType::Default => None,
+                    };
+                    break (ReturnOf::Closure, target, false);
+                }
+                Some(Syn::ExprAsync(..)) => {
+                    break (ReturnOf::AsyncBlock, async_block_output(enclosing), false)
+                }
+                Some(Syn::ExprTryBlock(..)) => break (ReturnOf::TryBlock, None, false),
+                Some(Syn::Item(..)) | Some(Syn::ImplItem(..)) | Some(Syn::TraitItem(..)) | None => {
+                    break (ReturnOf::Function, None, false)
+                }
+                _ => {}
+            }
+        };
+
+        let (returns, error) = match target {
+            Some(TryTarget::Unit) => (TryReturnType::Unit, None),
+            Some(TryTarget::Type(ty)) => classify(ty),
+            None => (TryReturnType::Unknown, None),
+        };
+
+        HelpItem::ExprTryQuestionMark {
+            of,
+            returns,
+            error,
+            main,
+        }
+    }
+}
+
+enum TryTarget<'a> {
+    /// Functions without a return type return `()`
+    Unit,
+    Type(&'a syn::Type),
+}
+
+fn fn_output(output: &syn::ReturnType) -> Option<TryTarget<'_>> {
+    Some(match output {
+        syn::ReturnType::Type(_, ty) => TryTarget::Type(ty),
+        syn::ReturnType::Default => TryTarget::Unit,
+    })
+}
+
+/// The output type of an async block that is the body of a function returning
+/// `impl Future<Output = T>`
+fn async_block_output<'a>(mut enclosing: impl Iterator<Item = Syn<'a>>) -> Option<TryTarget<'a>> {
+    let sig = match enclosing
+        .find(|node| !std::matches!(node, Syn::Expr(..) | Syn::Block(..) | Syn::Stmt(..)))?
+    {
+        Syn::ItemFn(item_fn) => &item_fn.sig,
+        Syn::ImplItemMethod(method) => &method.sig,
+        _ => return None,
+    };
+    let bounds = match &sig.output {
+        syn::ReturnType::Type(_, ty) => match &**ty {
+            syn::Type::ImplTrait(impl_trait) => &impl_trait.bounds,
+            _ => return None,
+        },
+        syn::ReturnType::Default => return None,
+    };
+
+    bounds.iter().find_map(|bound| {
+        let segment = match bound {
+            syn::TypeParamBound::Trait(trait_bound) => trait_bound.path.segments.last()?,
+            _ => return None,
+        };
+        if segment.ident != "Future" {
+            return None;
+        }
+        match &segment.arguments {
+            syn::PathArguments::AngleBracketed(arguments) => {
+                arguments.args.iter().find_map(|argument| match argument {
+                    syn::GenericArgument::Binding(binding) if binding.ident == "Output" => {
+                        Some(TryTarget::Type(&binding.ty))
+                    }
+                    _ => None,
+                })
+            }
+            _ => None,
+        }
+    })
+}
+
+/// The kind of value returned by `?` on failure, and the error type for `Result`s
+fn classify(ty: &syn::Type) -> (TryReturnType, Option<String>) {
+    match ty {
+        syn::Type::Tuple(tuple) if tuple.elems.is_empty() => (TryReturnType::Unit, None),
+        syn::Type::Paren(paren) => classify(&paren.elem),
+        syn::Type::ImplTrait(impl_trait) if impl_trait.bounds.iter().any(|bound| {
+            std::matches!(
+                bound,
+                syn::TypeParamBound::Trait(trait_bound)
+                    if trait_bound.path.segments.last().map(|segment| segment.ident == "Future").unwrap_or(false)
+            )
+        }) => (TryReturnType::Future, None),
+        syn::Type::Path(type_path) => {
+            let segment = match type_path.path.segments.last() {
+                Some(segment) => segment,
+                None => return (TryReturnType::Unknown, None),
+            };
+            let arguments: Vec<_> = match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => arguments
+                    .args
+                    .iter()
+                    .filter_map(|argument| match argument {
+                        syn::GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+
+            if segment.ident == "Result" {
+                // Aliases like `io::Result<T>` fix the error type
+                (
+                    TryReturnType::Result,
+                    arguments.get(1).map(tokens_to_string),
+                )
+            } else if segment.ident == "Option" {
+                (TryReturnType::Option, None)
+            } else {
+                (TryReturnType::Unknown, None)
+            }
+        }
+        _ => (TryReturnType::Unknown, None),
+    }
+}
@@ -0,0 +1,508 @@
+use super::{expressions::type_name, printing::tokens_to_string, Analyzer, NodeAnalyzer};
+use crate::ir::Location;
+use crate::syn_wrappers::Syn;
+use syn::spanned::Spanned;
+
+/// Enum variants from the std prelude, that can be used without a path
+pub(super) const PRELUDE_VARIANTS: &[(&str, &str)] = &[
+    ("Some", "Option"),
+    ("None", "Option"),
+    ("Ok", "Result"),
+    ("Err", "Result"),
+];
+
+/// Functions from the std prelude, that can be called without a path
+pub(super) const PRELUDE_FUNCTIONS: &[&str] = &["drop"];
+
+/// A method declared in an `impl` block or in a trait
+pub(super) struct MethodDefinition<'a> {
+    pub sig: &'a syn::Signature,
+    pub impl_: Option<&'a syn::ItemImpl>,
+    pub trait_: Option<&'a syn::ItemTrait>,
+}
+
+/// A local variable introduced by a `let` statement, a function or closure argument,
+/// or any other pattern
+pub(super) struct LocalBinding<'a> {
+    pub pat: &'a syn::PatIdent,
+    /// Only available if the binding is the whole pattern, e.g. `let x: u8 = ...`
+    pub ty: Option<&'a syn::Type>,
+    /// Only available if the binding is the whole pattern, e.g. `let x = ...`
+    pub init: Option<&'a syn::Expr>,
+}
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Every node in the file, in source order
+    pub(super) fn nodes(&self) -> impl Iterator<Item = Syn<'a>> {
+        let analyzer: &'a Analyzer = self.analyzer;
+        analyzer.node_map.iter().map(|(_, syn)| syn)
+    }
+    pub(super) fn find_struct(&self, name: &str) -> Option<&'a syn::ItemStruct> {
+        self.nodes().find_map(|syn| match syn {
+            Syn::ItemStruct(item) if item.ident == name => Some(item),
+            _ => None,
+        })
+    }
+    pub(super) fn find_enum(&self, name: &str) -> Option<&'a syn::ItemEnum> {
+        self.nodes().find_map(|syn| match syn {
+            Syn::ItemEnum(item) if item.ident == name => Some(item),
+            _ => None,
+        })
+    }
+    /// The enum declaring a variant named `name`
+    pub(super) fn find_variant(&self, name: &str) -> Option<&'a syn::ItemEnum> {
+        self.nodes().find_map(|syn| match syn {
+            Syn::ItemEnum(item) if item.variants.iter().any(|v| v.ident == name) => Some(item),
+            _ => None,
+        })
+    }
+    pub(super) fn find_fn(&self, name: &str) -> Option<&'a syn::ItemFn> {
+        self.nodes().find_map(|syn| match syn {
+            Syn::ItemFn(item) if item.sig.ident == name => Some(item),
+            _ => None,
+        })
+    }
+    pub(super) fn find_const(&self, name: &str) -> Option<&'a syn::ItemConst> {
+        self.nodes().find_map(|syn| match syn {
+            Syn::ItemConst(item) if item.ident == name => Some(item),
+            _ => None,
+        })
+    }
+    pub(super) fn find_trait(&self, name: &str) -> Option<&'a syn::ItemTrait> {
+        self.nodes().find_map(|syn| match syn {
+            Syn::ItemTrait(item) if item.ident == name => Some(item),
+            _ => None,
+        })
+    }
+    /// The trait declared in the file that a path refers to. Other paths, like
+    /// `std::fmt::Display`, name traits from other crates
+    pub(super) fn resolve_trait(&self, path: &syn::Path) -> Option<&'a syn::ItemTrait> {
+        let in_crate = path.leading_colon.is_none()
+            && (path.segments.len() == 1
+                || path
+                    .segments
+                    .first()
+                    .map(|segment| segment.ident == "crate" || segment.ident == "self")
+                    .unwrap_or(false));
+        if !in_crate {
+            return None;
+        }
+        self.find_trait(&path.segments.last()?.ident.to_string())
+    }
+    /// Whether the trait is implemented in the file for the type `ty`, or for any type through a
+    /// blanket implementation
+    pub(super) fn implements(&self, trait_: &syn::ItemTrait, ty: &syn::PathSegment) -> bool {
+        self.nodes().any(|syn| match syn {
+            Syn::ItemImpl(item) => {
+                let implemented = item
+                    .trait_
+                    .as_ref()
+                    .and_then(|(_, path, _)| self.resolve_trait(path))
+                    .map(|item_trait| std::ptr::eq(item_trait, trait_))
+                    .unwrap_or(false);
+                implemented && (is_blanket(item) || same_type(item, ty))
+            }
+            _ => false,
+        })
+    }
+    /// The implementation of the trait named `trait_name` for the type named `ty`
+    pub(super) fn find_trait_impl(&self, trait_name: &str, ty: &str) -> Option<&'a syn::ItemImpl> {
+        self.nodes().find_map(|syn| match syn {
+            Syn::ItemImpl(item)
+                if type_name(&item.self_ty).as_deref() == Some(ty)
+                    && item
+                        .trait_
+                        .as_ref()
+                        .and_then(|(_, path, _)| path.segments.last())
+                        .map(|segment| segment.ident == trait_name)
+                        .unwrap_or(false) =>
+            {
+                Some(item)
+            }
+            _ => None,
+        })
+    }
+    /// The enum declared in the file whose variant `name` is imported by a `use` declaration, like
+    /// `use Color::*`
+    pub(super) fn find_imported_variant(&self, name: &str) -> Option<&'a syn::ItemEnum> {
+        self.nodes().find_map(|syn| match syn {
+            Syn::ItemUse(item_use) => self.imported_variant(&item_use.tree, None, name),
+            _ => None,
+        })
+    }
+    fn imported_variant(
+        &self,
+        tree: &syn::UseTree,
+        parent: Option<&syn::Ident>,
+        name: &str,
+    ) -> Option<&'a syn::ItemEnum> {
+        let variant = match tree {
+            syn::UseTree::Path(use_path) => {
+                return self.imported_variant(&use_path.tree, Some(&use_path.ident), name)
+            }
+            syn::UseTree::Group(use_group) => {
+                return use_group
+                    .items
+                    .iter()
+                    .find_map(|tree| self.imported_variant(tree, parent, name))
+            }
+            syn::UseTree::Name(use_name) if use_name.ident == name => use_name.ident.to_string(),
+            syn::UseTree::Rename(use_rename) if use_rename.rename == name => {
+                use_rename.ident.to_string()
+            }
+            syn::UseTree::Glob(..) => name.to_string(),
+            _ => return None,
+        };
+        self.find_enum(&parent?.to_string())
+            .filter(|item| item.variants.iter().any(|v| v.ident == variant))
+    }
+    /// Methods (i.e. associated functions with a receiver) named `name`
+    pub(super) fn find_methods(&self, name: &syn::Ident) -> Vec<MethodDefinition<'a>> {
+        self.nodes()
+            .flat_map(|syn| {
+                let methods: Vec<_> = match syn {
+                    Syn::ItemImpl(item) => item
+                        .items
+                        .iter()
+                        .filter_map(|impl_item| match impl_item {
+                            syn::ImplItem::Method(method) => Some(MethodDefinition {
+                                sig: &method.sig,
+                                impl_: Some(item),
+                                trait_: None,
+                            }),
+                            _ => None,
+                        })
+                        .collect(),
+                    Syn::ItemTrait(item) => item
+                        .items
+                        .iter()
+                        .filter_map(|trait_item| match trait_item {
+                            syn::TraitItem::Method(method) => Some(MethodDefinition {
+                                sig: &method.sig,
+                                impl_: None,
+                                trait_: Some(item),
+                            }),
+                            _ => None,
+                        })
+                        .collect(),
+                    _ => vec![],
+                };
+                methods
+            })
+            .filter(|method| &method.sig.ident == name && method.sig.receiver().is_some())
+            .collect()
+    }
+    /// Whether `name` is a type (or a trait) declared somewhere in the file
+    pub(super) fn is_type_name(&self, name: &str) -> bool {
+        self.nodes().any(|syn| match syn {
+            Syn::ItemStruct(item) => item.ident == name,
+            Syn::ItemEnum(item) => item.ident == name,
+            Syn::ItemUnion(item) => item.ident == name,
+            Syn::ItemTrait(item) => item.ident == name,
+            Syn::ItemType(item) => item.ident == name,
+            _ => false,
+        })
+    }
+    /// Whether `name` is a struct, enum or union declared somewhere in the file
+    pub(super) fn is_local_type(&self, name: &str) -> bool {
+        self.nodes().any(|syn| match syn {
+            Syn::ItemStruct(item) => item.ident == name,
+            Syn::ItemEnum(item) => item.ident == name,
+            Syn::ItemUnion(item) => item.ident == name,
+            _ => false,
+        })
+    }
+    /// The implementing type of the closest enclosing `impl` block
+    pub(super) fn enclosing_self_ty(&self) -> Option<&'a syn::Type> {
+        self.ancestors
+            .iter()
+            .rev()
+            .find_map(|(_, node)| match node {
+                Syn::ItemImpl(item) => Some(&*item.self_ty),
+                _ => None,
+            })
+    }
+    /// The signature of the enclosing function, if the current node is not part of a function
+    /// pointer type or an `Fn` trait bound nested in it
+    pub(super) fn enclosing_signature(&self) -> Option<&'a syn::Signature> {
+        self.ancestors
+            .iter()
+            .rev()
+            .find_map(|(_, node)| match node {
+                Syn::Signature(sig) => Some(Some(*sig)),
+                Syn::TypeBareFn(..) | Syn::ParenthesizedGenericArguments(..) => Some(None),
+                _ => None,
+            })?
+    }
+    /// The trait named by the closest enclosing path segment, e.g. `Iterator` in
+    /// `Iterator<Item = u8>`
+    pub(super) fn constrained_trait(&self) -> Option<String> {
+        self.ancestors
+            .iter()
+            .rev()
+            .find_map(|(_, node)| match node {
+                Syn::PathSegment(segment) => Some(segment.ident.to_string()),
+                _ => None,
+            })
+    }
+    /// Finds the local binding for `ident` that is visible at the current location.
+    ///
+    /// The search stops at the closest enclosing item: locals are never visible in nested items.
+    pub(super) fn find_local(&self, ident: &syn::Ident) -> Option<LocalBinding<'a>> {
+        let location = self.location;
+        let before = |node: &dyn Spanned| Location::from(node.span().end()) <= location;
+
+        for (_, node) in self.ancestors.iter().rev() {
+            let found = match node {
+                Syn::Block(block) => block
+                    .stmts
+                    .iter()
+                    .rev()
+                    .filter(|stmt| before(*stmt))
+                    .find_map(|stmt| match stmt {
+                        syn::Stmt::Local(local) => local_binding(local, ident),
+                        _ => None,
+                    }),
+                Syn::ExprClosure(closure) if !closure.inputs.iter().any(|pat| self.within(pat)) => {
+                    closure
+                        .inputs
+                        .iter()
+                        .find_map(|pat| pat_binding(pat, ident))
+                }
+                Syn::ExprForLoop(for_loop) if self.within(&for_loop.body) => {
+                    pat_binding(&for_loop.pat, ident)
+                }
+                Syn::Arm(arm) if !self.within(&arm.pat) => pat_binding(&arm.pat, ident),
+                Syn::ExprIf(syn::ExprIf {
+                    cond, then_branch, ..
+                }) if self.within(then_branch) => match &**cond {
+                    syn::Expr::Let(expr_let) => pat_binding(&expr_let.pat, ident),
+                    _ => None,
+                },
+                Syn::ExprWhile(syn::ExprWhile { cond, body, .. }) if self.within(body) => {
+                    match &**cond {
+                        syn::Expr::Let(expr_let) => pat_binding(&expr_let.pat, ident),
+                        _ => None,
+                    }
+                }
+                Syn::ItemFn(syn::ItemFn { sig, .. })
+                | Syn::ImplItemMethod(syn::ImplItemMethod { sig, .. })
+                | Syn::TraitItemMethod(syn::TraitItemMethod { sig, .. }) => {
+                    return sig.inputs.iter().find_map(|arg| match arg {
+                        syn::FnArg::Typed(pat_type) => {
+                            pat_binding(&pat_type.pat, ident).map(|binding| LocalBinding {
+                                ty: match &*pat_type.pat {
+                                    syn::Pat::Ident(..) => Some(&*pat_type.ty),
+                                    _ => None,
+                                },
+                                ..binding
+                            })
+                        }
+                        syn::FnArg::Receiver(..) => None,
+                    });
+                }
+                Syn::Item(..) | Syn::ImplItem(..) | Syn::TraitItem(..) => return None,
+                _ => None,
+            };
+
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        None
+    }
+}
+
+fn local_binding<'a>(local: &'a syn::Local, ident: &syn::Ident) -> Option<LocalBinding<'a>> {
+    let binding = pat_binding(&local.pat, ident)?;
+    let whole_pattern = match &local.pat {
+        syn::Pat::Ident(_) => true,
+        syn::Pat::Type(pat_type) => std::matches!(&*pat_type.pat, syn::Pat::Ident(..)),
+        _ => false,
+    };
+
+    Some(LocalBinding {
+        init: local
+            .init
+            .as_ref()
+            .map(|(_, init)| &**init)
+            .filter(|_| whole_pattern),
+        ..binding
+    })
+}
+
+fn pat_binding<'a>(pat: &'a syn::Pat, ident: &syn::Ident) -> Option<LocalBinding<'a>> {
+    let ty = match pat {
+        syn::Pat::Type(pat_type) => match &*pat_type.pat {
+            syn::Pat::Ident(..) => Some(&*pat_type.ty),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    bound_idents(pat)
+        .into_iter()
+        .find(|pat_ident| &pat_ident.ident == ident)
+        .map(|pat| LocalBinding {
+            pat,
+            ty,
+            init: None,
+        })
+}
+
+/// The identifiers bound by a pattern.
+///
+/// Capitalized identifiers are assumed to be constants or unit variants, not bindings.
+pub(super) fn bound_idents(pat: &syn::Pat) -> Vec<&syn::PatIdent> {
+    let mut idents = vec![];
+    let mut pending = vec![pat];
+
+    while let Some(pat) = pending.pop() {
+        match pat {
+            syn::Pat::Box(pat_box) => pending.push(&pat_box.pat),
+            syn::Pat::Ident(pat_ident) => {
+                if !is_capitalized(&pat_ident.ident) {
+                    idents.push(pat_ident);
+                }
+                if let Some((_, subpat)) = &pat_ident.subpat {
+                    pending.push(subpat);
+                }
+            }
+            // Every case must bind the same set of names
+            syn::Pat::Or(pat_or) => pending.extend(pat_or.cases.first()),
+            syn::Pat::Reference(pat_ref) => pending.push(&pat_ref.pat),
+            syn::Pat::Slice(pat_slice) => pending.extend(pat_slice.elems.iter().rev()),
+            syn::Pat::Struct(pat_struct) => {
+                pending.extend(pat_struct.fields.iter().rev().map(|field| &*field.pat))
+            }
+            syn::Pat::Tuple(pat_tuple) => pending.extend(pat_tuple.elems.iter().rev()),
+            syn::Pat::TupleStruct(pat_tuple_struct) => {
+                pending.extend(pat_tuple_struct.pat.elems.iter().rev())
+            }
+            syn::Pat::Type(pat_type) => pending.push(&pat_type.pat),
+            _ => {}
+        }
+    }
+
+    idents
+}
+
+pub(super) fn is_capitalized(ident: &syn::Ident) -> bool {
+    ident
+        .to_string()
+        .trim_start_matches("r#")
+        .starts_with(|c: char| c.is_uppercase())
+}
+
+/// Whether the implementation is for the type `ty`, comparing the last segment of their paths and
+/// their generic arguments. Type parameters of the implementation match any argument
+pub(super) fn same_type(item: &syn::ItemImpl, ty: &syn::PathSegment) -> bool {
+    let segment = match &*item.self_ty {
+        syn::Type::Path(type_path) => match type_path.path.segments.last() {
+            Some(segment) => segment,
+            None => return false,
+        },
+        _ => return false,
+    };
+    if segment.ident != ty.ident {
+        return false;
+    }
+
+    let (impl_args, args) = match (&segment.arguments, &ty.arguments) {
+        (
+            syn::PathArguments::AngleBracketed(impl_args),
+            syn::PathArguments::AngleBracketed(args),
+        ) => (impl_args, args),
+        // The arguments aren't always known, e.g. for `let v = Wrapper::new();`
+        _ => return true,
+    };
+    impl_args.args.len() == args.args.len()
+        && impl_args
+            .args
+            .iter()
+            .zip(&args.args)
+            .all(|(impl_arg, arg)| match impl_arg {
+                syn::GenericArgument::Lifetime(..) => true,
+                syn::GenericArgument::Type(ty) if is_type_param(item, ty) => true,
+                impl_arg => tokens_to_string(impl_arg) == tokens_to_string(arg),
+            })
+}
+
+/// An implementation for any type, like `impl<T: Display> Show for T`
+pub(super) fn is_blanket(item: &syn::ItemImpl) -> bool {
+    match &*item.self_ty {
+        syn::Type::Reference(type_ref) => is_type_param(item, &type_ref.elem),
+        ty => is_type_param(item, ty),
+    }
+}
+
+fn is_type_param(item: &syn::ItemImpl, ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .get_ident()
+            .map(|ident| {
+                item.generics
+                    .type_params()
+                    .any(|param| &param.ident == ident)
+            })
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// The visibility of an item node
+pub(super) fn item_vis<'a>(node: Syn<'a>) -> Option<&'a syn::Visibility> {
+    match node {
+        Syn::ItemConst(item) => Some(&item.vis),
+        Syn::ItemEnum(item) => Some(&item.vis),
+        Syn::ItemExternCrate(item) => Some(&item.vis),
+        Syn::ItemFn(item) => Some(&item.vis),
+        Syn::ItemMod(item) => Some(&item.vis),
+        Syn::ItemStatic(item) => Some(&item.vis),
+        Syn::ItemStruct(item) => Some(&item.vis),
+        Syn::ItemTrait(item) => Some(&item.vis),
+        Syn::ItemTraitAlias(item) => Some(&item.vis),
+        Syn::ItemType(item) => Some(&item.vis),
+        Syn::ItemUnion(item) => Some(&item.vis),
+        Syn::ItemUse(item) => Some(&item.vis),
+        _ => None,
+    }
+}
+
+/// The name of an item node
+pub(super) fn item_ident<'a>(node: Syn<'a>) -> Option<&'a syn::Ident> {
+    match node {
+        Syn::ItemConst(item) => Some(&item.ident),
+        Syn::ItemEnum(item) => Some(&item.ident),
+        Syn::ItemFn(item) => Some(&item.sig.ident),
+        Syn::ItemMod(item) => Some(&item.ident),
+        Syn::ItemStatic(item) => Some(&item.ident),
+        Syn::ItemStruct(item) => Some(&item.ident),
+        Syn::ItemTrait(item) => Some(&item.ident),
+        Syn::ItemTraitAlias(item) => Some(&item.ident),
+        Syn::ItemType(item) => Some(&item.ident),
+        Syn::ItemUnion(item) => Some(&item.ident),
+        _ => None,
+    }
+}
+
+/// The node of an item, like `Syn::ItemStruct` for `syn::Item::Struct`
+pub(super) fn item_node<'a>(item: &'a syn::Item) -> Option<Syn<'a>> {
+    match item {
+        syn::Item::Const(item) => Some(Syn::ItemConst(item)),
+        syn::Item::Enum(item) => Some(Syn::ItemEnum(item)),
+        syn::Item::ExternCrate(item) => Some(Syn::ItemExternCrate(item)),
+        syn::Item::Fn(item) => Some(Syn::ItemFn(item)),
+        syn::Item::Mod(item) => Some(Syn::ItemMod(item)),
+        syn::Item::Static(item) => Some(Syn::ItemStatic(item)),
+        syn::Item::Struct(item) => Some(Syn::ItemStruct(item)),
+        syn::Item::Trait(item) => Some(Syn::ItemTrait(item)),
+        syn::Item::TraitAlias(item) => Some(Syn::ItemTraitAlias(item)),
+        syn::Item::Type(item) => Some(Syn::ItemType(item)),
+        syn::Item::Union(item) => Some(Syn::ItemUnion(item)),
+        syn::Item::Use(item) => Some(Syn::ItemUse(item)),
+        _ => None,
+    }
+}
@@ -0,0 +1,230 @@
+use super::{resolution::bound_idents, NodeAnalyzer};
+use crate::{
+    help::{HelpItem, ScopeOf},
+    ir::{Location, Range},
+    syn_wrappers::{Syn, SynKind},
+};
+use syn::spanned::Spanned;
+
+/// The part of the code where a binding can be used
+pub(super) struct Scope {
+    pub of: ScopeOf,
+    pub range: Range,
+    /// Whether the binding is shadowed by a later `let` before the end of the block
+    pub shadowed: bool,
+    /// Whether the binding is part of a larger pattern
+    pub destructured: bool,
+}
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Computes the syntactic scope of the binding introduced by `node`: from the point where it
+    /// is introduced until the end of the enclosing block, or until it is shadowed
+    pub(super) fn binding_scope(&self, node: &syn::PatIdent) -> Option<Scope> {
+        let mut destructured = false;
+        let mut ancestors = self
+            .ancestors
+            .iter()
+            .rev()
+            .map(|(_, node)| *node)
+            .skip_while(|node| match node {
+                Syn::Pat(..) | Syn::PatType(..) => true,
+                Syn::FieldPat(..)
+                | Syn::PatBox(..)
+                | Syn::PatIdent(..)
+                | Syn::PatOr(..)
+                | Syn::PatReference(..)
+                | Syn::PatSlice(..)
+                | Syn::PatStruct(..)
+                | Syn::PatTuple(..)
+                | Syn::PatTupleStruct(..) => {
+                    destructured = true;
+                    true
+                }
+                _ => false,
+            });
+
+        let (of, range, stmts): (_, Range, &[syn::Stmt]) = match ancestors.next()? {
+            Syn::Local(local) => {
+                let block = ancestors.find_map(|node| match node {
+                    Syn::Block(block) => Some(block),
+                    _ => None,
+                })?;
+                let range = (local.span().end().into(), block.span().end().into());
+                (ScopeOf::Block, range, &block.stmts)
+            }
+            Syn::FnArg(..) => {
+                let block = match (ancestors.next()?, ancestors.next()?) {
+                    (Syn::Signature(..), Syn::ItemFn(item_fn)) => &*item_fn.block,
+                    (Syn::Signature(..), Syn::ImplItemMethod(method)) => &method.block,
+                    (Syn::Signature(..), Syn::TraitItemMethod(method)) => {
+                        method.default.as_ref()?
+                    }
+                    _ => return None,
+                };
+                (ScopeOf::Fn, span_range(block), &block.stmts)
+            }
+            // Right after the opening `|`, the closure itself is explained instead
+            Syn::ExprClosure(closure)
+                if Location::from(closure.or1_token.spans[0].end()) == self.location =>
+            {
+                return None
+            }
+            Syn::ExprClosure(closure) => (
+                ScopeOf::Closure,
+                span_range(&closure.body),
+                block_stmts(&closure.body),
+            ),
+            Syn::Arm(arm) => {
+                // Bindings can be used in the guard as well
+                let start = match &arm.guard {
+                    Some((if_token, _)) => if_token.span,
+                    None => arm.fat_arrow_token.spans[0],
+                };
+                let range = (start.start().into(), arm.body.span().end().into());
+                (ScopeOf::Arm, range, block_stmts(&arm.body))
+            }
+            Syn::ExprForLoop(for_loop) if self.within(&for_loop.pat) => (
+                ScopeOf::Loop,
+                span_range(&for_loop.body),
+                &for_loop.body.stmts,
+            ),
+            Syn::ExprLet(..) => match ancestors.find(|node| !std::matches!(node, Syn::Expr(..)))? {
+                Syn::ExprIf(expr_if) => {
+                    let block = &expr_if.then_branch;
+                    (ScopeOf::IfLet, span_range(block), &block.stmts)
+                }
+                Syn::ExprWhile(expr_while) => {
+                    let block = &expr_while.body;
+                    (ScopeOf::Loop, span_range(block), &block.stmts)
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(shadowing(
+            Scope {
+                of,
+                range,
+                shadowed: false,
+                destructured,
+            },
+            stmts,
+            &node.ident,
+        ))
+    }
+}
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Explains the order in which the locals of a block are dropped, when the cursor is on one
+    /// of its braces
+    pub(super) fn visit_block(&mut self, node: &syn::Block) {
+        // Braces around a const generic argument don't introduce a scope
+        if self.has_ancestor(3, SynKind::GenericArgument) {
+            return;
+        }
+
+        let span = node.brace_token.span;
+        let (start, end): (Location, Location) = (span.start().into(), span.end().into());
+        let open = (
+            start,
+            Location {
+                column: start.column + 1,
+                ..start
+            },
+        );
+        let close = (
+            Location {
+                column: end.column.saturating_sub(1),
+                ..end
+            },
+            end,
+        );
+
+        let brace = match [open, close]
+            .iter()
+            .find(|(start, end)| *start <= self.location && self.location <= *end)
+        {
+            Some(brace) => *brace,
+            None => return,
+        };
+
+        // Function arguments live in an outer scope, so they are dropped after the body's locals
+        let args = match self.ancestors.last().map(|(_, node)| *node) {
+            Some(Syn::ItemFn(syn::ItemFn { sig, .. }))
+            | Some(Syn::ImplItemMethod(syn::ImplItemMethod { sig, .. }))
+            | Some(Syn::TraitItemMethod(syn::TraitItemMethod { sig, .. })) => sig
+                .inputs
+                .iter()
+                .rev()
+                .flat_map(|arg| match arg {
+                    syn::FnArg::Receiver(receiver) if receiver.reference.is_none() => {
+                        vec!["self".to_string()]
+                    }
+                    syn::FnArg::Receiver(..) => vec![],
+                    syn::FnArg::Typed(pat_type) => dropped_bindings(&pat_type.pat),
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        let locals = node
+            .stmts
+            .iter()
+            .rev()
+            .flat_map(|stmt| match stmt {
+                syn::Stmt::Local(local) => dropped_bindings(&local.pat),
+                _ => vec![],
+            })
+            .collect();
+
+        self.help = Some((brace, HelpItem::Block { locals, args }));
+    }
+}
+
+/// Names bound by `pat`, in the order they are dropped
+fn dropped_bindings(pat: &syn::Pat) -> Vec<String> {
+    bound_idents(pat)
+        .iter()
+        .rev()
+        .map(|pat_ident| pat_ident.ident.to_string())
+        .collect()
+}
+
+fn span_range(node: &dyn Spanned) -> Range {
+    let span = node.span();
+    (span.start().into(), span.end().into())
+}
+
+fn block_stmts(expr: &syn::Expr) -> &[syn::Stmt] {
+    match expr {
+        syn::Expr::Block(expr_block) => &expr_block.block.stmts,
+        _ => &[],
+    }
+}
+
+/// Cuts the scope short at the first `let` statement that introduces a binding with the same
+/// name. The shadowing statement is included, since its initializer can still use the binding
+fn shadowing(scope: Scope, stmts: &[syn::Stmt], ident: &syn::Ident) -> Scope {
+    let start = scope.range.0;
+    let shadowing = stmts.iter().find_map(|stmt| match stmt {
+        syn::Stmt::Local(local)
+            if Location::from(local.span().start()) >= start
+                && bound_idents(&local.pat)
+                    .iter()
+                    .any(|pat_ident| pat_ident.ident == *ident) =>
+        {
+            Some(local)
+        }
+        _ => None,
+    });
+
+    match shadowing {
+        Some(local) => Scope {
+            range: (start, local.span().end().into()),
+            shadowed: true,
+            ..scope
+        },
+        None => scope,
+    }
+}
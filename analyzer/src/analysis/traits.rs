@@ -0,0 +1,258 @@
+use super::NodeAnalyzer;
+use crate::help::ObjectSafety;
+use syn::visit::Visit;
+
+/// Supertraits of well-known std traits
+const STD_SUPERTRAITS: &[(&str, &[&str])] = &[
+    ("Copy", &["Clone"]),
+    ("Eq", &["PartialEq"]),
+    ("PartialOrd", &["PartialEq"]),
+    ("Ord", &["Eq", "PartialOrd"]),
+    ("DerefMut", &["Deref"]),
+    ("IndexMut", &["Index"]),
+    ("Fn", &["FnMut"]),
+    ("FnMut", &["FnOnce"]),
+    ("DoubleEndedIterator", &["Iterator"]),
+    ("ExactSizeIterator", &["Iterator"]),
+    ("FusedIterator", &["Iterator"]),
+    ("Error", &["Debug", "Display"]),
+];
+
+/// Std traits that can't be used as trait objects
+const STD_NOT_OBJECT_SAFE: &[&str] = &[
+    "Clone",
+    "Copy",
+    "Default",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "FromStr",
+];
+
+impl<'a> NodeAnalyzer<'a> {
+    /// The direct and indirect supertraits of a trait, following traits declared in the file
+    /// and well-known std traits
+    pub(super) fn supertraits(&self, node: &syn::ItemTrait) -> Vec<String> {
+        self.collect_supertraits(&node.ident.to_string(), direct_supertraits(node))
+    }
+
+    fn collect_supertraits(&self, name: &str, mut pending: Vec<String>) -> Vec<String> {
+        let mut supertraits: Vec<String> = vec![];
+
+        while !pending.is_empty() {
+            let supertrait = pending.remove(0);
+            if name == supertrait || supertraits.contains(&supertrait) {
+                continue;
+            }
+            match self.find_trait(&supertrait) {
+                Some(item_trait) => pending.extend(direct_supertraits(item_trait)),
+                None => pending.extend(std_supertraits(&supertrait)),
+            }
+            supertraits.push(supertrait);
+        }
+
+        supertraits
+    }
+
+    /// The supertraits and the object safety of the trait of a trait object, if the trait is
+    /// declared in the file or is a well-known std trait
+    pub(super) fn trait_object_info(
+        &self,
+        path: &syn::Path,
+    ) -> (Vec<String>, Option<ObjectSafety>) {
+        if let Some(item_trait) = self.resolve_trait(path) {
+            return (
+                self.supertraits(item_trait),
+                Some(self.object_safety(item_trait)),
+            );
+        }
+
+        let name = match path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => return (vec![], None),
+        };
+        let supertraits = self.collect_supertraits(&name, std_supertraits(&name));
+        let unsafe_supertraits: Vec<_> = supertraits
+            .iter()
+            .filter(|supertrait| STD_NOT_OBJECT_SAFE.contains(&supertrait.as_str()))
+            .cloned()
+            .collect();
+        // Other std traits may not be object safe either, so only violations are reported
+        let safety =
+            if STD_NOT_OBJECT_SAFE.contains(&name.as_str()) || !unsafe_supertraits.is_empty() {
+                Some(ObjectSafety {
+                    safe: false,
+                    supertraits: unsafe_supertraits,
+                    generic: vec![],
+                    self_: vec![],
+                    no_receiver: vec![],
+                    consts: vec![],
+                    sized: false,
+                })
+            } else {
+                None
+            };
+
+        (supertraits, safety)
+    }
+
+    /// Checks the rules for object safety that can be verified syntactically, on the trait and
+    /// on its supertraits
+    pub(super) fn object_safety(&self, node: &syn::ItemTrait) -> ObjectSafety {
+        let supertraits = self.supertraits(node);
+        let sized = supertraits.iter().any(|name| name == "Sized");
+        let supertraits: Vec<_> = supertraits
+            .into_iter()
+            .filter(|name| match self.find_trait(name) {
+                Some(item_trait) => !Violations::of(item_trait).is_empty(),
+                None => STD_NOT_OBJECT_SAFE.contains(&name.as_str()),
+            })
+            .collect();
+        let violations = Violations::of(node);
+
+        ObjectSafety {
+            safe: !sized && supertraits.is_empty() && violations.is_empty(),
+            supertraits,
+            generic: violations.generic,
+            self_: violations.self_,
+            no_receiver: violations.no_receiver,
+            consts: violations.consts,
+            sized,
+        }
+    }
+}
+
+/// The items of a trait that prevent it from being used as a trait object. Methods bounded by
+/// `where Self: Sized` are not callable on trait objects, so they are exempt
+#[derive(Default)]
+struct Violations {
+    generic: Vec<String>,
+    self_: Vec<String>,
+    no_receiver: Vec<String>,
+    consts: Vec<String>,
+}
+
+impl Violations {
+    fn of(node: &syn::ItemTrait) -> Self {
+        let mut violations = Violations::default();
+
+        for item in &node.items {
+            match item {
+                syn::TraitItem::Const(constant) => {
+                    violations.consts.push(constant.ident.to_string())
+                }
+                syn::TraitItem::Method(method) if !requires_sized(&method.sig.generics) => {
+                    let sig = &method.sig;
+                    let name = sig.ident.to_string();
+                    if sig.generics.type_params().next().is_some()
+                        || sig.generics.const_params().next().is_some()
+                    {
+                        violations.generic.push(name.clone());
+                    }
+
+                    if sig.receiver().is_none() {
+                        violations.no_receiver.push(name);
+                        continue;
+                    }
+                    let mut finder = SelfFinder(false);
+                    sig.inputs
+                        .iter()
+                        .skip(1)
+                        .for_each(|arg| finder.visit_fn_arg(arg));
+                    finder.visit_return_type(&sig.output);
+                    if finder.0 {
+                        violations.self_.push(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        violations
+    }
+
+    fn is_empty(&self) -> bool {
+        self.generic.is_empty()
+            && self.self_.is_empty()
+            && self.no_receiver.is_empty()
+            && self.consts.is_empty()
+    }
+}
+
+fn std_supertraits(name: &str) -> Vec<String> {
+    STD_SUPERTRAITS
+        .iter()
+        .filter(|(std_trait, _)| *std_trait == name)
+        .flat_map(|(_, supertraits)| supertraits.iter().map(ToString::to_string))
+        .collect()
+}
+
+/// Supertraits in the declaration of a trait, and in `where Self: ..` predicates
+fn direct_supertraits(node: &syn::ItemTrait) -> Vec<String> {
+    let where_bounds = node
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .filter_map(|predicate| match predicate {
+            syn::WherePredicate::Type(predicate) if is_self(&predicate.bounded_ty) => {
+                Some(predicate.bounds.iter())
+            }
+            _ => None,
+        })
+        .flatten();
+
+    node.supertraits
+        .iter()
+        .chain(where_bounds)
+        .filter_map(|bound| match bound {
+            syn::TypeParamBound::Trait(trait_bound)
+                if std::matches!(trait_bound.modifier, syn::TraitBoundModifier::None) =>
+            {
+                Some(trait_bound.path.segments.last()?.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether `generics` have a `where Self: Sized` predicate
+fn requires_sized(generics: &syn::Generics) -> bool {
+    generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .any(|predicate| match predicate {
+            syn::WherePredicate::Type(predicate) => {
+                is_self(&predicate.bounded_ty)
+                    && predicate.bounds.iter().any(|bound| match bound {
+                        syn::TypeParamBound::Trait(trait_bound) => {
+                            trait_bound.path.is_ident("Sized")
+                        }
+                        _ => false,
+                    })
+            }
+            _ => false,
+        })
+}
+
+fn is_self(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident("Self"),
+        _ => false,
+    }
+}
+
+/// Looks for `Self` types, but not for associated types like `Self::Item`
+struct SelfFinder(bool);
+
+impl<'ast> Visit<'ast> for SelfFinder {
+    fn visit_type(&mut self, node: &'ast syn::Type) {
+        if is_self(node) {
+            self.0 = true;
+        }
+        syn::visit::visit_type(self, node);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{generics::Generics, NodeAnalyzer};
+use super::{casts::primitive_named, generics::Generics, printing::tokens_to_string, NodeAnalyzer};
 use crate::{
     help::GenericsOf,
     syn_wrappers::{Syn, SynKind},
@@ -12,7 +12,15 @@ impl<'a> NodeAnalyzer<'a> {
         if self.between_locations(node.span().start(), node.elem.span().start())
             || self.between_spans(node.semi_token.span(), node.span())
         {
-            return self.set_help(node, HelpItem::TypeArray);
+            let (value, unevaluated) = self.evaluate_const(&node.len, primitive_named("usize"));
+            return self.set_help(
+                node,
+                HelpItem::TypeArray {
+                    len: tokens_to_string(&node.len),
+                    value,
+                    unevaluated,
+                },
+            );
         }
     }
     pub(super) fn visit_type_bare_fn_first_pass(&mut self, node: &syn::TypeBareFn) {
@@ -113,6 +121,10 @@ impl<'a> NodeAnalyzer<'a> {
             if self.within(lifetime) {}
         }
 
+        if let Some(item) = self.elided_lifetime_help(node) {
+            return self.set_help(node, item);
+        }
+
         if let syn::Type::Path(type_path) = &*node.elem {
             if let Some(HelpItem::KnownTypeStr) = well_known_type(type_path) {
                 return self.set_help(
@@ -187,6 +199,8 @@ impl<'a> NodeAnalyzer<'a> {
             .next()
             .is_some();
 
+        let (supertraits, safety) = self.trait_object_info(&ty.path);
+
         return self.set_help(
             node,
             HelpItem::TypeTraitObject {
@@ -194,6 +208,8 @@ impl<'a> NodeAnalyzer<'a> {
                 multiple,
                 dyn_: node.dyn_token.is_some(),
                 ty: ty.path.to_token_stream().to_string(),
+                supertraits,
+                safety,
             },
         );
     }
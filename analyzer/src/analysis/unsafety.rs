@@ -0,0 +1,459 @@
+use super::{expressions::type_name, resolution::is_capitalized, NodeAnalyzer};
+use crate::{help::HelpItem, ir::Range, syn_wrappers::Syn};
+use proc_macro2::Span;
+use std::collections::{HashMap, HashSet};
+use syn::{parse::ParseStream, punctuated::Punctuated, spanned::Spanned, visit::Visit};
+
+/// Macros that expand to inline assembly
+const ASM_MACROS: &[&str] = &["asm", "llvm_asm"];
+
+/// Macros of the standard library that don't expand to unsafe operations themselves, although
+/// their arguments can contain some
+const SAFE_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "dbg",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "eprint",
+    "eprintln",
+    "format",
+    "panic",
+    "print",
+    "println",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "vec",
+    "write",
+    "writeln",
+];
+
+/// Methods of raw pointers that return another raw pointer
+const POINTER_METHODS: &[&str] = &[
+    "add",
+    "cast",
+    "cast_const",
+    "cast_mut",
+    "offset",
+    "sub",
+    "wrapping_add",
+    "wrapping_offset",
+    "wrapping_sub",
+];
+
+/// An operation that is only allowed in an unsafe context
+pub struct UnsafeOperation {
+    pub range: Range,
+    /// Short description, like `raw pointer dereference`
+    pub label: String,
+}
+
+/// Items declared in the file that can only be used in an unsafe context
+#[derive(Default)]
+struct UnsafeItems {
+    functions: HashSet<String>,
+    /// Functions that can be called without `unsafe`
+    safe_functions: HashSet<String>,
+    extern_functions: HashSet<String>,
+    /// Methods of the `impl` blocks, by type and name, and whether they are `unsafe`
+    methods: HashMap<(String, String), bool>,
+    static_muts: HashSet<String>,
+    extern_statics: HashSet<String>,
+    /// Fields of unions that no struct in the file has
+    union_fields: HashSet<String>,
+}
+
+impl<'a> NodeAnalyzer<'a> {
+    /// Every operation that requires the `unsafe` block, or the body of the `unsafe fn`, whose
+    /// `unsafe` keyword is at the current location. Nested `unsafe` blocks and items are not
+    /// included, since they don't need the outer unsafe context
+    pub(super) fn unsafe_operations(&self, node: Syn<'a>) -> Option<Vec<UnsafeOperation>> {
+        self.unsafe_collector(node)
+            .map(|collector| collector.operations)
+    }
+
+    pub(super) fn unsafe_block_help(&self, node: Syn<'a>) -> HelpItem {
+        let collector = self.unsafe_collector(node);
+        HelpItem::ExprUnsafe {
+            operations: collector
+                .as_ref()
+                .map(|collector| labels(&collector.operations))
+                .unwrap_or_default(),
+            unresolved: collector
+                .map(|collector| collector.unresolved)
+                .unwrap_or(false),
+        }
+    }
+
+    /// The distinct labels of the operations that require the `unsafe` keyword at the current
+    /// location, in source order
+    pub(super) fn unsafe_operation_labels(&self, node: Syn<'a>) -> Vec<String> {
+        labels(&self.unsafe_operations(node).unwrap_or_default())
+    }
+
+    fn unsafe_collector(&self, node: Syn<'a>) -> Option<UnsafeCollector> {
+        let (unsafe_token, block, sig) = match node {
+            Syn::ExprUnsafe(expr_unsafe) => (expr_unsafe.unsafe_token, &expr_unsafe.block, None),
+            Syn::ItemFn(item_fn) => (item_fn.sig.unsafety?, &*item_fn.block, Some(&item_fn.sig)),
+            Syn::ImplItemMethod(method) => (method.sig.unsafety?, &method.block, Some(&method.sig)),
+            Syn::TraitItemMethod(method) => (
+                method.sig.unsafety?,
+                method.default.as_ref()?,
+                Some(&method.sig),
+            ),
+            _ => return None,
+        };
+        if !self.within(unsafe_token) {
+            return None;
+        }
+
+        // Raw pointers are recognized by name, from the bindings of the whole function
+        let mut pointers = PointerBindings::default();
+        if let Some(fn_sig) = sig.or_else(|| self.enclosing_signature()) {
+            pointers.visit_signature(fn_sig);
+        }
+        match sig {
+            Some(..) => pointers.visit_block(block),
+            None => pointers.visit_block(self.enclosing_fn_block().unwrap_or(block)),
+        }
+
+        let mut collector = UnsafeCollector {
+            items: self.unsafe_items(),
+            self_ty: self.enclosing_self_ty().and_then(type_name),
+            pointers: pointers.names,
+            operations: vec![],
+            unresolved: false,
+        };
+        for stmt in &block.stmts {
+            collector.visit_stmt(stmt);
+        }
+
+        collector
+            .operations
+            .sort_by_key(|operation| operation.range);
+        Some(collector)
+    }
+
+    /// The body of the closest enclosing function
+    fn enclosing_fn_block(&self) -> Option<&'a syn::Block> {
+        self.ancestors
+            .iter()
+            .rev()
+            .find_map(|(_, node)| match node {
+                Syn::ItemFn(item_fn) => Some(Some(&*item_fn.block)),
+                Syn::ImplItemMethod(method) => Some(Some(&method.block)),
+                Syn::TraitItemMethod(method) => Some(method.default.as_ref()),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    fn unsafe_items(&self) -> UnsafeItems {
+        let mut items = UnsafeItems::default();
+        let mut struct_fields = HashSet::new();
+        let mut union_fields = HashSet::new();
+
+        for node in self.nodes() {
+            match node {
+                Syn::ItemFn(item_fn) if item_fn.sig.unsafety.is_some() => {
+                    items.functions.insert(item_fn.sig.ident.to_string());
+                }
+                Syn::ItemFn(item_fn) => {
+                    items.safe_functions.insert(item_fn.sig.ident.to_string());
+                }
+                Syn::ItemImpl(item_impl) => {
+                    let self_ty = match type_name(&item_impl.self_ty) {
+                        Some(self_ty) => self_ty,
+                        None => continue,
+                    };
+                    for item in &item_impl.items {
+                        if let syn::ImplItem::Method(method) = item {
+                            items.methods.insert(
+                                (self_ty.clone(), method.sig.ident.to_string()),
+                                method.sig.unsafety.is_some(),
+                            );
+                        }
+                    }
+                }
+                Syn::ForeignItemFn(foreign_fn) => {
+                    items
+                        .extern_functions
+                        .insert(foreign_fn.sig.ident.to_string());
+                }
+                Syn::ItemStatic(item_static) if item_static.mutability.is_some() => {
+                    items.static_muts.insert(item_static.ident.to_string());
+                }
+                Syn::ForeignItemStatic(foreign_static) => {
+                    items
+                        .extern_statics
+                        .insert(foreign_static.ident.to_string());
+                }
+                Syn::ItemStruct(item_struct) => {
+                    struct_fields.extend(field_names(&item_struct.fields));
+                }
+                Syn::ItemUnion(item_union) => {
+                    union_fields.extend(field_names(&item_union.fields.named));
+                }
+                _ => {}
+            }
+        }
+
+        items.union_fields = &union_fields - &struct_fields;
+        items
+    }
+}
+
+/// The distinct labels of the operations, in order
+fn labels(operations: &[UnsafeOperation]) -> Vec<String> {
+    let mut labels: Vec<String> = vec![];
+    for operation in operations {
+        if !labels.contains(&operation.label) {
+            labels.push(operation.label.clone());
+        }
+    }
+    labels
+}
+
+fn field_names<'a>(fields: impl IntoIterator<Item = &'a syn::Field>) -> Vec<String> {
+    fields
+        .into_iter()
+        .filter_map(|field| field.ident.as_ref())
+        .map(|ident| ident.to_string())
+        .collect()
+}
+
+/// Names of the bindings that hold a raw pointer, ignoring shadowing
+#[derive(Default)]
+struct PointerBindings {
+    names: HashSet<String>,
+}
+
+impl PointerBindings {
+    fn push_typed(&mut self, pat_type: &syn::PatType) {
+        if let (syn::Pat::Ident(pat_ident), syn::Type::Ptr(..)) = (&*pat_type.pat, &*pat_type.ty) {
+            self.names.insert(pat_ident.ident.to_string());
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for PointerBindings {
+    fn visit_fn_arg(&mut self, node: &'ast syn::FnArg) {
+        if let syn::FnArg::Typed(pat_type) = node {
+            self.push_typed(pat_type);
+        }
+    }
+
+    fn visit_local(&mut self, node: &'ast syn::Local) {
+        match &node.pat {
+            syn::Pat::Type(pat_type) => self.push_typed(pat_type),
+            syn::Pat::Ident(pat_ident) => {
+                if let Some((_, init)) = &node.init {
+                    if is_pointer(init, &self.names) {
+                        self.names.insert(pat_ident.ident.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+        syn::visit::visit_local(self, node);
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast syn::ExprClosure) {
+        for input in &node.inputs {
+            if let syn::Pat::Type(pat_type) = input {
+                self.push_typed(pat_type);
+            }
+        }
+        syn::visit::visit_expr_closure(self, node);
+    }
+
+    fn visit_item(&mut self, _: &'ast syn::Item) {}
+}
+
+/// Whether `expr` evaluates to a raw pointer, as far as can be told without type information
+fn is_pointer(expr: &syn::Expr, pointers: &HashSet<String>) -> bool {
+    match expr {
+        syn::Expr::Paren(syn::ExprParen { expr, .. })
+        | syn::Expr::Group(syn::ExprGroup { expr, .. }) => is_pointer(expr, pointers),
+        syn::Expr::Cast(expr_cast) => std::matches!(&*expr_cast.ty, syn::Type::Ptr(..)),
+        syn::Expr::Path(expr_path) => expr_path
+            .path
+            .get_ident()
+            .map(|ident| pointers.contains(&ident.to_string()))
+            .unwrap_or(false),
+        syn::Expr::MethodCall(method_call) => {
+            let method = method_call.method.to_string();
+            method == "as_ptr"
+                || method == "as_mut_ptr"
+                || (POINTER_METHODS.contains(&method.as_str())
+                    && is_pointer(&method_call.receiver, pointers))
+        }
+        syn::Expr::Call(call) => match &*call.func {
+            syn::Expr::Path(expr_path) => expr_path
+                .path
+                .segments
+                .last()
+                .map(|segment| segment.ident == "null" || segment.ident == "null_mut")
+                .unwrap_or(false),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+struct UnsafeCollector {
+    items: UnsafeItems,
+    /// The type of the enclosing `impl` block
+    self_ty: Option<String>,
+    pointers: HashSet<String>,
+    operations: Vec<UnsafeOperation>,
+    /// Whether the block calls functions, methods or macros that aren't declared in the file,
+    /// which may be unsafe
+    unresolved: bool,
+}
+
+impl UnsafeCollector {
+    fn push(&mut self, span: Span, label: String) {
+        self.operations.push(UnsafeOperation {
+            range: (span.start().into(), span.end().into()),
+            label,
+        });
+    }
+
+    /// Whether the method `method` of the type `ty` is `unsafe`, if it's declared in the file
+    fn method(&self, ty: &syn::Ident, method: &syn::Ident) -> Option<bool> {
+        let ty = if ty == "Self" {
+            self.self_ty.clone()?
+        } else {
+            ty.to_string()
+        };
+        self.items.methods.get(&(ty, method.to_string())).copied()
+    }
+}
+
+impl<'ast> Visit<'ast> for UnsafeCollector {
+    fn visit_expr_unary(&mut self, node: &'ast syn::ExprUnary) {
+        if let syn::UnOp::Deref(..) = node.op {
+            if is_pointer(&node.expr, &self.pointers) {
+                self.push(node.span(), "raw pointer dereference".to_string());
+            }
+        }
+        syn::visit::visit_expr_unary(self, node);
+    }
+
+    fn visit_expr_path(&mut self, node: &'ast syn::ExprPath) {
+        if node.qself.is_none() {
+            if let Some(ident) = node.path.get_ident().map(|ident| ident.to_string()) {
+                if self.items.static_muts.contains(&ident) {
+                    self.push(node.span(), "static mut access".to_string());
+                } else if self.items.extern_statics.contains(&ident) {
+                    self.push(node.span(), "extern static access".to_string());
+                }
+            }
+        }
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+        let segments: Vec<_> = match &*node.func {
+            syn::Expr::Path(expr_path) if expr_path.qself.is_none() => {
+                expr_path.path.segments.iter().map(|s| &s.ident).collect()
+            }
+            _ => vec![],
+        };
+        match &segments[..] {
+            [ident] if self.items.functions.contains(&ident.to_string()) => {
+                self.push(node.func.span(), "unsafe fn call".to_string())
+            }
+            [ident] if self.items.extern_functions.contains(&ident.to_string()) => {
+                self.push(node.func.span(), "extern fn call".to_string())
+            }
+            // Tuple structs and variants
+            [.., ident] if is_capitalized(ident) => {}
+            [ident] if self.items.safe_functions.contains(&ident.to_string()) => {}
+            [.., ty, method] => match self.method(ty, method) {
+                Some(true) => self.push(node.func.span(), "unsafe fn call".to_string()),
+                Some(false) => {}
+                None => self.unresolved = true,
+            },
+            _ => self.unresolved = true,
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast syn::ExprMethodCall) {
+        // Without type information, only methods called on `self` can be resolved
+        let on_self = match &*node.receiver {
+            syn::Expr::Path(expr_path) => expr_path.path.is_ident("self"),
+            _ => false,
+        };
+        let self_ty = syn::Ident::new("Self", node.method.span());
+        match self.method(&self_ty, &node.method).filter(|_| on_self) {
+            Some(true) => self.push(node.method.span(), "unsafe fn call".to_string()),
+            Some(false) => {}
+            None => self.unresolved = true,
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_field(&mut self, node: &'ast syn::ExprField) {
+        if let syn::Member::Named(ident) = &node.member {
+            if self.items.union_fields.contains(&ident.to_string()) {
+                self.push(node.span(), "union field read".to_string());
+            }
+        }
+        syn::visit::visit_expr_field(self, node);
+    }
+
+    fn visit_expr_assign(&mut self, node: &'ast syn::ExprAssign) {
+        // Writing to a union field is safe, only its base is visited
+        match &*node.left {
+            syn::Expr::Field(expr_field) => self.visit_expr(&expr_field.base),
+            left => self.visit_expr(left),
+        }
+        self.visit_expr(&node.right);
+    }
+
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        let name = match node.path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => return,
+        };
+        if ASM_MACROS.contains(&name.as_str()) {
+            return self.push(node.span(), format!("{}!", name));
+        }
+
+        match macro_args(node) {
+            Ok(args) if SAFE_MACROS.contains(&name.as_str()) => {
+                args.iter().for_each(|arg| self.visit_expr(arg))
+            }
+            _ => self.unresolved = true,
+        }
+    }
+
+    // Nested `unsafe` blocks don't need the outer one
+    fn visit_expr_unsafe(&mut self, _: &'ast syn::ExprUnsafe) {}
+    fn visit_item(&mut self, node: &'ast syn::Item) {
+        // Macros in statement position are parsed as items
+        if let syn::Item::Macro(item_macro) = node {
+            self.visit_macro(&item_macro.mac);
+        }
+    }
+}
+
+/// The arguments of a macro, as comma-separated expressions or as the `value; length` form of
+/// `vec!`
+fn macro_args(node: &syn::Macro) -> syn::Result<Vec<syn::Expr>> {
+    node.parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+        .map(|args| args.into_iter().collect())
+        .or_else(|_| {
+            node.parse_body_with(|input: ParseStream| {
+                let value = input.parse()?;
+                input.parse::<syn::Token![;]>()?;
+                let len = input.parse()?;
+                Ok(vec![value, len])
+            })
+        })
+}
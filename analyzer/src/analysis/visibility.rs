@@ -0,0 +1,185 @@
+use super::{
+    resolution::{item_ident, item_vis},
+    NodeAnalyzer,
+};
+use crate::{help::ItemVisibility, ir::NodeId, syn_wrappers::Syn};
+
+/// A module, as the path of its ancestors starting from the crate root
+pub(super) type ModulePath = Vec<String>;
+
+/// The module within which something is visible, or `None` if it's visible everywhere
+pub(super) type Scope = Option<ModulePath>;
+
+impl<'a> NodeAnalyzer<'a> {
+    /// The canonical path and the effective visibility of the item or field that owns the
+    /// current visibility node
+    pub(super) fn owner_visibility(&self) -> Option<ItemVisibility> {
+        // The owner comes right before the `Visibility` node
+        let position = self
+            .ancestors
+            .iter()
+            .rposition(|(_, node)| std::matches!(node, Syn::Visibility(..)))?
+            .checked_sub(1)?;
+        let (_, owner) = self.ancestors[position];
+
+        match owner {
+            Syn::Field(field) => self.field_visibility(field, &self.ancestors[..position]),
+            owner => {
+                let vis = item_vis(owner)?;
+                effective_visibility(vis, item_ident(owner), &self.ancestors[..position])
+            }
+        }
+    }
+
+    /// The inline module containing the current location
+    pub(super) fn current_module(&self) -> ModulePath {
+        self.ancestors
+            .iter()
+            .filter_map(|(_, node)| match node {
+                Syn::ItemMod(item_mod) => Some(item_mod.ident.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The canonical path and the effective visibility of the item at the current location
+    pub(super) fn item_visibility(
+        &self,
+        vis: &syn::Visibility,
+        ident: &syn::Ident,
+    ) -> Option<ItemVisibility> {
+        effective_visibility(vis, Some(ident), self.ancestors)
+    }
+
+    /// Fields of a struct or union are visible at most as much as their struct
+    fn field_visibility(
+        &self,
+        field: &syn::Field,
+        containers: &[(NodeId, Syn<'a>)],
+    ) -> Option<ItemVisibility> {
+        // Skip the `Fields` and `FieldsNamed` nodes
+        let position = containers
+            .iter()
+            .rposition(|(_, node)| std::matches!(node, Syn::ItemStruct(..) | Syn::ItemUnion(..)))?;
+        let (vis, ident) = match containers[position].1 {
+            Syn::ItemStruct(item) => (&item.vis, &item.ident),
+            Syn::ItemUnion(item) => (&item.vis, &item.ident),
+            _ => return None,
+        };
+
+        let module = module_path(&containers[..position])?;
+        let (struct_scope, _) = effective_scope(vis, &containers[..position])?;
+        let field_scope = scope(&field.vis, &module);
+        let limited = narrower(&struct_scope, &field_scope);
+        let effective = if limited { struct_scope } else { field_scope };
+
+        Some(ItemVisibility {
+            path: None,
+            reachable: effective.as_ref().map(|scope| render(scope)),
+            limited_by: if limited {
+                Some(render(&[&module[..], &[ident.to_string()]].concat()))
+            } else {
+                None
+            },
+        })
+    }
+}
+
+/// The canonical path and the effective visibility of an item declared within `containers`
+fn effective_visibility(
+    vis: &syn::Visibility,
+    ident: Option<&syn::Ident>,
+    containers: &[(NodeId, Syn)],
+) -> Option<ItemVisibility> {
+    let module = module_path(containers)?;
+    let (scope, limited_by) = effective_scope(vis, containers)?;
+
+    Some(ItemVisibility {
+        path: ident.map(|ident| render(&[&module[..], &[ident.to_string()]].concat())),
+        reachable: scope.as_ref().map(|scope| render(scope)),
+        limited_by: limited_by.map(|module| render(&module)),
+    })
+}
+
+/// The scope within which an item is reachable, taking into account the visibility of the
+/// modules it's nested in, and the module that restricts it, if any
+fn effective_scope(
+    vis: &syn::Visibility,
+    containers: &[(NodeId, Syn)],
+) -> Option<(Scope, Option<ModulePath>)> {
+    let module = module_path(containers)?;
+    let mut effective = scope(vis, &module);
+    let mut limited_by = None;
+
+    // From the innermost module outwards, so that the closest restricting module is reported
+    for depth in (1..=module.len()).rev() {
+        let item_mod = containers
+            .iter()
+            .filter_map(|(_, node)| match node {
+                Syn::ItemMod(item_mod) => Some(item_mod),
+                _ => None,
+            })
+            .nth(depth - 1)?;
+        let module_scope = scope(&item_mod.vis, &module[..depth - 1]);
+        if narrower(&module_scope, &effective) {
+            effective = module_scope;
+            if limited_by.is_none() {
+                limited_by = Some(module[..depth].to_vec());
+            }
+        }
+    }
+
+    Some((effective, limited_by))
+}
+
+/// The path of the inline modules containing an item, or `None` if the item is not reachable
+/// through a path, e.g. because it's declared in a function body
+fn module_path(containers: &[(NodeId, Syn)]) -> Option<ModulePath> {
+    containers
+        .iter()
+        .filter_map(|(_, node)| match node {
+            Syn::File(..) | Syn::Item(..) => None,
+            Syn::ItemMod(item_mod) => Some(Some(item_mod.ident.to_string())),
+            _ => Some(None),
+        })
+        .collect()
+}
+
+/// The scope of a visibility declared in `module`
+pub(super) fn scope(vis: &syn::Visibility, module: &[String]) -> Scope {
+    match vis {
+        syn::Visibility::Public(..) => None,
+        syn::Visibility::Crate(..) => Some(vec![]),
+        syn::Visibility::Inherited => Some(module.to_vec()),
+        syn::Visibility::Restricted(restricted) => {
+            let mut scope = module.to_vec();
+            for (index, segment) in restricted.path.segments.iter().enumerate() {
+                match segment.ident.to_string().as_str() {
+                    "crate" if index == 0 => scope.clear(),
+                    "self" if index == 0 => {}
+                    "super" => {
+                        scope.pop();
+                    }
+                    name => scope.push(name.to_string()),
+                }
+            }
+            Some(scope)
+        }
+    }
+}
+
+/// Whether `scope` is strictly smaller than `other`
+fn narrower(scope: &Scope, other: &Scope) -> bool {
+    match (scope, other) {
+        (Some(scope), Some(other)) => scope.len() > other.len(),
+        (Some(..), None) => true,
+        (None, _) => false,
+    }
+}
+
+pub(super) fn render(module: &[String]) -> String {
+    std::iter::once("crate")
+        .chain(module.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join("::")
+}
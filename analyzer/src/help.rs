@@ -38,24 +38,66 @@ std::include!(concat!(env!("OUT_DIR"), "/help.rs"));
 #[serde(tag = "type")]
 pub enum HelpItem {
     Unknown,
-    AddBinOp,
-    SubBinOp,
-    MulBinOp,
-    DivBinOp,
-    RemBinOp,
-    AndBinOp,
-    OrBinOp,
-    BitXorBinOp,
-    BitAndBinOp,
-    BitOrBinOp,
-    ShlBinOp,
-    ShrBinOp,
-    EqBinOp,
-    LtBinOp,
-    LeBinOp,
-    NeBinOp,
-    GeBinOp,
-    GtBinOp,
+    AddBinOp {
+        grouping: Option<String>,
+    },
+    SubBinOp {
+        grouping: Option<String>,
+    },
+    MulBinOp {
+        grouping: Option<String>,
+    },
+    DivBinOp {
+        grouping: Option<String>,
+    },
+    RemBinOp {
+        grouping: Option<String>,
+    },
+    AndBinOp {
+        grouping: Option<String>,
+    },
+    OrBinOp {
+        grouping: Option<String>,
+    },
+    BitXorBinOp {
+        grouping: Option<String>,
+    },
+    BitAndBinOp {
+        grouping: Option<String>,
+    },
+    BitOrBinOp {
+        grouping: Option<String>,
+    },
+    ShlBinOp {
+        grouping: Option<String>,
+    },
+    ShrBinOp {
+        grouping: Option<String>,
+    },
+    EqBinOp {
+        grouping: Option<String>,
+        chained: bool,
+    },
+    LtBinOp {
+        grouping: Option<String>,
+        chained: bool,
+    },
+    LeBinOp {
+        grouping: Option<String>,
+        chained: bool,
+    },
+    NeBinOp {
+        grouping: Option<String>,
+        chained: bool,
+    },
+    GeBinOp {
+        grouping: Option<String>,
+        chained: bool,
+    },
+    GtBinOp {
+        grouping: Option<String>,
+        chained: bool,
+    },
     AddEqBinOp,
     SubEqBinOp,
     MulEqBinOp,
@@ -68,6 +110,13 @@ pub enum HelpItem {
     ShrEqBinOp,
     Binding {
         ident: String,
+        trait_: Option<String>,
+        ty: String,
+    },
+    Constraint {
+        ident: String,
+        trait_: Option<String>,
+        bounds: String,
     },
     ExprArray,
     ExprArraySlice,
@@ -81,10 +130,25 @@ pub enum HelpItem {
         label: Option<String>,
         expr: bool,
     },
+    ExprCall {
+        of: CallOf,
+        name: String,
+        parent: Option<String>,
+        in_file: bool,
+    },
     ExprClosure,
-    ExprClosureArguments,
+    ExprClosureArguments {
+        by_ref: Vec<String>,
+        by_unknown: Vec<String>,
+        by_mut: Vec<String>,
+        by_move: Vec<String>,
+        fn_trait: ClosureTraitBound,
+    },
     ExprClosureAsync,
-    ExprClosureMove,
+    ExprClosureMove {
+        by_move: Vec<String>,
+        fn_trait: ClosureTraitBound,
+    },
     ExprClosureStatic,
     ExprContinue {
         label: Option<String>,
@@ -106,7 +170,19 @@ pub enum HelpItem {
         range: bool,
     },
     ExprLoopToken,
-    ExprMatchToken,
+    ExprMatchToken {
+        enum_: Option<String>,
+        uncovered: Vec<String>,
+        catch_all: bool,
+        guard: bool,
+    },
+    ExprMethodCall {
+        method: String,
+        self_ty: Option<String>,
+        trait_: Option<String>,
+        receiver: Option<SelfKind>,
+        line: Option<usize>,
+    },
     ExprRangeHalfOpen {
         from: bool,
         to: bool,
@@ -120,20 +196,32 @@ pub enum HelpItem {
     },
     ExprRepeat {
         len: String,
+        value: Option<String>,
+        unevaluated: Option<ConstEvalError>,
     },
     ExprReturn {
         of: ReturnOf,
     },
     ExprStruct,
     ExprStructRest,
-    ExprTryQuestionMark,
+    ExprTryQuestionMark {
+        of: ReturnOf,
+        returns: TryReturnType,
+        /// The error type of the returned `Result`, if known
+        error: Option<String>,
+        main: bool,
+    },
     ExprTryBlock,
     ExprTuple {
         single_comma: bool,
     },
     ExprUnitTuple,
     ExprType,
-    ExprUnsafe,
+    ExprUnsafe {
+        operations: Vec<String>,
+        /// Whether the block calls functions, methods or macros that aren't declared in the file
+        unresolved: bool,
+    },
     ExprWhileLet,
     ExprWhile,
     ExprYield,
@@ -141,11 +229,16 @@ pub enum HelpItem {
     MacroTokens,
     Turbofish,
     ParenthesizedGenericArguments,
+    GenericArgumentConst {
+        value: Option<String>,
+        unevaluated: Option<ConstEvalError>,
+    },
     PatBox,
     PatIdent {
         mutability: bool,
         by_ref: bool,
         ident: String,
+        default_binding: Option<BindingMode>,
     },
     PatIdentSubPat {
         ident: String,
@@ -154,17 +247,50 @@ pub enum HelpItem {
     PatIdentMutableArg {
         ident: String,
     },
+    PatIdentScope {
+        ident: String,
+        of: ScopeOf,
+        shadowed: bool,
+    },
+    PatLit,
+    PatMacro,
     PatOrLeading,
     PatOr,
+    PatPath {
+        name: String,
+        of: PathPatOf,
+        refutable: bool,
+    },
     PatRange {
         closed: bool,
     },
+    PatReference {
+        mutability: bool,
+        refutable: bool,
+    },
+    PatRefutable {
+        of: BindingOf,
+        pattern: String,
+        /// The matched value, or the iterator of a `for` loop
+        scrutinee: Option<String>,
+    },
     PatRest {
         of: RestOf,
     },
+    PatSlice {
+        len: usize,
+        rest: bool,
+        default_binding: Option<BindingMode>,
+        refutable: bool,
+    },
+    PatSliceRest {
+        ident: Option<String>,
+    },
     PatStruct {
         empty: bool,
         bindings: Option<BindingOf>,
+        default_binding: Option<BindingMode>,
+        refutable: bool,
     },
     // TODO: watch out for tuple struct/variants with no fields
     // https://play.rust-lang.org/?version=stable&mode=debug&edition=2018&gist=005c87b573205fe29993d051eebbd011
@@ -172,9 +298,17 @@ pub enum HelpItem {
     PatTuple {
         bindings: Option<BindingOf>,
         single_comma: bool,
+        default_binding: Option<BindingMode>,
+        refutable: bool,
     },
     PatTupleStruct {
         bindings: Option<BindingOf>,
+        default_binding: Option<BindingMode>,
+        refutable: bool,
+    },
+    PatType {
+        ident: Option<String>,
+        of: AnnotationOf,
     },
     PatWild {
         last_arm: bool,
@@ -204,9 +338,15 @@ pub enum HelpItem {
         known: Option<KnownAttribute>,
     },
     ItemExternCrate,
-    ItemFn,
-    ItemInlineMod,
-    ItemExternMod,
+    ItemFn {
+        visibility: Option<ItemVisibility>,
+    },
+    ItemInlineMod {
+        visibility: Option<ItemVisibility>,
+    },
+    ItemExternMod {
+        visibility: Option<ItemVisibility>,
+    },
     TraitItemMethod {
         of: FnOf,
         default: bool,
@@ -223,11 +363,28 @@ pub enum HelpItem {
     AsRenameExternCrate {
         underscore: bool,
     },
-    AsCast,
+    AsCast {
+        /// The type of the casted expression, if it can be known syntactically
+        from: Option<String>,
+        to: String,
+        conversion: CastKind,
+        /// The value of the cast, for literals
+        result: Option<String>,
+    },
     AsyncFn,
-    ImplItemConst,
+    ImplItemConst {
+        name: String,
+        /// Only for traits declared in the same file
+        trait_: Option<String>,
+        ty: Option<String>,
+        line: Option<usize>,
+    },
     TraitItemConst,
-    ItemConst,
+    ItemConst {
+        value: Option<String>,
+        unevaluated: Option<ConstEvalError>,
+        visibility: Option<ItemVisibility>,
+    },
     ConstParam {
         name: String,
         of: GenericsOf,
@@ -238,19 +395,39 @@ pub enum HelpItem {
     ConstFn,
     VisPublic {
         field: bool,
+        visibility: Option<ItemVisibility>,
+    },
+    VisCrate {
+        visibility: Option<ItemVisibility>,
     },
-    VisCrate,
     VisRestricted {
         path: VisRestrictedPath,
         in_: bool,
+        visibility: Option<ItemVisibility>,
     },
     WhereClause,
+    PredicateEq,
+    PredicateLifetime {
+        lifetime: String,
+        bounds: Vec<String>,
+    },
+    TypeLifetimeBound {
+        ty: String,
+        lifetime: String,
+        static_: bool,
+    },
     Variant {
         name: String,
         fields: Option<Fields>,
+        /// Only for variants of fieldless enums
+        discriminant: Option<String>,
+        unevaluated: Option<ConstEvalError>,
     },
     VariantDiscriminant {
         name: String,
+        /// Only if the discriminant is not a literal
+        value: Option<String>,
+        unevaluated: Option<ConstEvalError>,
     },
     ItemForeignModAbi,
     FnAbi,
@@ -262,11 +439,48 @@ pub enum HelpItem {
     },
     BoundLifetimesBareFnType,
     ItemImpl {
-        trait_: bool,
-        // TODO: negative impls should be more visible, in the bang symbol itself
+        trait_: Option<String>,
         negative: bool,
+        self_ty: String,
+        /// Has type or const parameters
+        generic: bool,
+        /// Implements a trait for a type parameter, like `impl<T: Display> Trait for T`
+        blanket: bool,
+        /// Whether the trait is declared in the same file
+        local_trait: bool,
+        /// Whether the implementing type, or one of the trait arguments, is declared in the same
+        /// file
+        local_type: bool,
+    },
+    PrivacyViolation {
+        of: PrivacyViolationOf,
+        /// The item, field or struct that can't be named
+        name: String,
+        /// The module declaring the item, or the struct declaring the field
+        declared_in: String,
+        visible_in: String,
+        /// The current module
+        from: String,
+        /// For constructors, the fields that are not visible
+        fields: Vec<String>,
+    },
+    ItemImplNegative {
+        trait_: String,
+        self_ty: String,
+    },
+    ItemImplForTrait {
+        trait_: String,
+        /// Whether the trait is declared in the same file, so that its items can be compared
+        local: bool,
+        /// Required items of the trait that the implementation lacks
+        missing: Vec<String>,
+        /// Items with a default in the trait that the implementation provides anyway
+        overridden: Vec<String>,
+        /// Items of the implementation that are not part of the trait
+        unknown: Vec<String>,
+        /// Whether the implementation invokes macros, which may expand to the missing items
+        macros: bool,
     },
-    ItemImplForTrait,
     // TODO: more details on definition, like matchers and stuff
     ItemMacroRules {
         name: String,
@@ -277,6 +491,12 @@ pub enum HelpItem {
         ident: Option<String>,
         mutability: bool,
     },
+    Block {
+        /// In drop order
+        locals: Vec<String>,
+        /// Function arguments, if this is a function body
+        args: Vec<String>,
+    },
     Label {
         loop_of: LoopOf,
     },
@@ -325,8 +545,19 @@ pub enum HelpItem {
         explicit: bool,
         mutability: bool,
     },
-    StaticMut,
-    Static,
+    StaticMut {
+        visibility: Option<ItemVisibility>,
+    },
+    Static {
+        visibility: Option<ItemVisibility>,
+    },
+    ElidedLifetime {
+        ty: String,
+        source: ElisionSource,
+        input: Option<String>,
+        lifetime: Option<String>,
+        annotated: Option<String>,
+    },
     // TODO: handle special cases: &dyn Foo, &(dyn Foo)
     TypeReference {
         mutable: bool,
@@ -342,6 +573,9 @@ pub enum HelpItem {
         lifetime: Option<String>,
         multiple: bool,
         dyn_: bool,
+        /// Only for traits declared in the same file, and well-known std traits
+        supertraits: Vec<String>,
+        safety: Option<ObjectSafety>,
     },
     UseGlob,
     UseGroup {
@@ -352,34 +586,66 @@ pub enum HelpItem {
     },
     ForeignItemType,
     RawIdent,
-    ImplItemType,
+    ImplItemType {
+        name: String,
+        /// Only for traits declared in the same file, and well-known std traits
+        trait_: Option<String>,
+        bounds: Vec<String>,
+        /// The line of the declaration, for traits declared in the same file
+        line: Option<usize>,
+        /// The path of the documentation of the associated type, for well-known std traits
+        std_docs: Option<String>,
+    },
     ItemUnsafeImpl,
     ItemEnum {
         empty: bool,
         generic: bool,
+        /// Variants and their discriminants, like `A = 0`, for fieldless enums
+        discriminants: Vec<String>,
+        visibility: Option<ItemVisibility>,
     },
     ItemStruct {
         unit: bool,
         name: String,
         generic: bool,
+        /// In drop order
+        fields: Vec<String>,
+        drop_impl: bool,
+        visibility: Option<ItemVisibility>,
     },
     ItemAutoTrait,
     ItemUnsafeTrait,
     ItemTrait {
         generic: bool,
+        /// Direct and indirect supertraits
+        supertraits: Vec<String>,
+        safety: ObjectSafety,
+        visibility: Option<ItemVisibility>,
+    },
+    ItemTraitSupertraits {
+        supertraits: Vec<String>,
+        safety: ObjectSafety,
+    },
+    ItemType {
+        visibility: Option<ItemVisibility>,
     },
-    ItemTraitSupertraits,
-    ItemType,
     ItemUnion {
         generic: bool,
+        visibility: Option<ItemVisibility>,
     },
     ItemUse,
-    UnsafeFn,
+    UnsafeFn {
+        operations: Vec<String>,
+    },
     TraitBoundModifierQuestion {
         sized: bool,
     },
     TraitItemType,
-    TypeArray,
+    TypeArray {
+        len: String,
+        value: Option<String>,
+        unevaluated: Option<ConstEvalError>,
+    },
     TypeBareFn,
     TypeBareFnAbi,
     TypeBareUnsafeFn,
@@ -498,6 +764,26 @@ help_data![
     }
 ];
 
+help_data![
+    pub enum AnnotationOf {
+        #[serde(rename(serialize = "variable"))]
+        Let,
+        #[serde(rename(serialize = "function argument"))]
+        Arg,
+        #[serde(rename(serialize = "closure argument"))]
+        ClosureArg,
+    }
+];
+
+help_data![
+    pub enum BindingMode {
+        #[serde(rename(serialize = "ref"))]
+        Ref,
+        #[serde(rename(serialize = "ref mut"))]
+        RefMut,
+    }
+];
+
 help_data![
     pub enum IntMode {
         Binary,
@@ -515,6 +801,17 @@ help_data![
     }
 ];
 
+help_data![
+    pub enum PathPatOf {
+        #[serde(rename(serialize = "unit variant"))]
+        UnitVariant,
+        #[serde(rename(serialize = "unit struct"))]
+        UnitStruct,
+        #[serde(rename(serialize = "constant"))]
+        Const,
+    }
+];
+
 help_data![
     pub enum FnOf {
         Method,
@@ -523,6 +820,26 @@ help_data![
     }
 ];
 
+help_data![
+    pub enum PrivacyViolationOf {
+        Item,
+        Field,
+        Constructor,
+    }
+];
+
+help_data![
+    pub enum SelfKind {
+        #[serde(rename(serialize = "self"))]
+        Value,
+        #[serde(rename(serialize = "&self"))]
+        Ref,
+        #[serde(rename(serialize = "&mut self"))]
+        RefMut,
+        Special,
+    }
+];
+
 help_data![
     pub enum Fields {
         Named,
@@ -540,6 +857,96 @@ help_data![
         Closure,
         #[serde(rename(serialize = "async block"))]
         AsyncBlock,
+        #[serde(rename(serialize = "try block"))]
+        TryBlock,
+    }
+];
+
+help_data![
+    pub enum CastKind {
+        Identity,
+        Truncate,
+        ZeroExtend,
+        SignExtend,
+        Reinterpret,
+        PointerSized,
+        FloatToInt,
+        IntToFloat,
+        FloatWiden,
+        FloatNarrow,
+        ByteToChar,
+        CharToInt,
+        BoolToInt,
+        PointerToInt,
+        PointerToPointer,
+        Invalid,
+        Unknown,
+    }
+];
+
+help_data![
+    pub enum ConstEvalError {
+        #[serde(rename(serialize = "it depends on a const generic parameter"))]
+        Generic,
+        #[serde(rename(serialize = "it uses a constant that isn't defined in this file"))]
+        UnknownConst,
+        #[serde(rename(serialize = "the computation overflows"))]
+        Overflow,
+        #[serde(rename(serialize = "it divides by zero"))]
+        DivisionByZero,
+        #[serde(rename(serialize = "the constant is defined in terms of itself"))]
+        Cycle,
+        #[serde(rename(
+            serialize = "it uses expressions that can't be evaluated here, like function calls"
+        ))]
+        Unsupported,
+    }
+];
+
+help_data![
+    pub enum TryReturnType {
+        Result,
+        Option,
+        Future,
+        Unit,
+        Unknown,
+    }
+];
+
+help_data![
+    pub enum CallOf {
+        Function,
+        #[serde(rename(serialize = "associated function"))]
+        AssociatedFunction,
+        #[serde(rename(serialize = "tuple struct"))]
+        TupleStruct,
+        #[serde(rename(serialize = "tuple variant"))]
+        TupleVariant,
+        Closure,
+        #[serde(rename(serialize = "function pointer"))]
+        FnPointer,
+        Local,
+        Expression,
+    }
+];
+
+help_data![
+    pub enum ClosureTrait {
+        #[serde(rename(serialize = "Fn"))]
+        Fn,
+        #[serde(rename(serialize = "FnMut"))]
+        FnMut,
+        #[serde(rename(serialize = "FnOnce"))]
+        FnOnce,
+    }
+];
+
+help_data![
+    pub struct ClosureTraitBound {
+        pub trait_: ClosureTrait,
+        /// Captures are used by methods declared outside of the file, which may need more than a
+        /// shared reference, so `trait_` is only the most general trait the closure can implement
+        pub uncertain: bool,
     }
 ];
 
@@ -554,6 +961,17 @@ help_data![
     }
 ];
 
+help_data![
+    pub enum ScopeOf {
+        Block,
+        Fn,
+        Closure,
+        Arm,
+        Loop,
+        IfLet,
+    }
+];
+
 help_data![
     pub enum VisRestrictedPath {
         Crate,
@@ -564,6 +982,50 @@ help_data![
     }
 ];
 
+help_data![
+    pub enum ElisionSource {
+        #[serde(rename(serialize = "self"))]
+        SelfRef,
+        #[serde(rename(serialize = "only input"))]
+        OnlyInput,
+        #[serde(rename(serialize = "no inputs"))]
+        NoInputs,
+        Ambiguous,
+    }
+];
+
+/// Whether a trait can be used as a trait object, and the items that prevent it
+#[cfg_attr(all(not(test), not(feature = "dev")), derive(Serialize))]
+#[cfg_attr(test, derive(Debug, Clone, Serialize, Deserialize, PartialEq))]
+#[cfg_attr(feature = "dev", derive(Debug, Clone, Serialize))]
+pub struct ObjectSafety {
+    pub safe: bool,
+    /// Supertraits that are not object safe themselves
+    pub supertraits: Vec<String>,
+    /// Methods with type or const parameters
+    pub generic: Vec<String>,
+    /// Methods that use `Self` outside of their receiver
+    pub self_: Vec<String>,
+    /// Associated functions without a receiver
+    pub no_receiver: Vec<String>,
+    pub consts: Vec<String>,
+    /// `Sized` is one of the supertraits
+    pub sized: bool,
+}
+
+/// Where an item can be named from
+#[cfg_attr(all(not(test), not(feature = "dev")), derive(Serialize))]
+#[cfg_attr(test, derive(Debug, Clone, Serialize, Deserialize, PartialEq))]
+#[cfg_attr(feature = "dev", derive(Debug, Clone, Serialize))]
+pub struct ItemVisibility {
+    /// The canonical path, for items
+    pub path: Option<String>,
+    /// The module outside of which the item can't be named, if any
+    pub reachable: Option<String>,
+    /// The module (or struct, for fields) that restricts the declared visibility
+    pub limited_by: Option<String>,
+}
+
 help_data![
     pub enum KnownAttribute {
         Doc,
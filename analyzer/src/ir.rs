@@ -118,6 +118,14 @@ impl NodeMap {
     pub fn syn_to_id(&self, syn: Syn) -> Option<NodeId> {
         self.element_to_id.get(&syn.into()).cloned()
     }
+
+    /// All nodes, in the order they were visited (i.e. source order)
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, Syn<'_>)> {
+        self.id_to_node
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (NodeId(idx), unsafe { node.element.as_syn() }))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -6,6 +6,6 @@ mod syn_wrappers;
 #[cfg(test)]
 mod tests;
 
-pub use analysis::{Analyzer, ExplorationIterator, ExplorationState};
+pub use analysis::{Analyzer, ExitPoint, ExplorationIterator, ExplorationState, UnsafeOperation};
 pub use help::{HelpInfoBit, HelpItem};
 pub use ir::IrVisitor;
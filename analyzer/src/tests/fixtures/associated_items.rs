@@ -0,0 +1,63 @@
+span: [6, 4] => [6, 8]
+item: ImplItemType name: Output, trait_: Shape, bounds: [Clone, "Into<f64>"], line: 2, std_docs: null
+naked: true
+---
+trait Shape {
+    type Output: Clone + Into<f64>;
+    const SIDES: u32;
+}
+impl Shape for Square {
+    <|>type Output = f32;
+    const SIDES: u32 = 4;
+}
+
+---
+span: [7, 4] => [7, 9]
+item: ImplItemConst name: SIDES, trait_: Shape, ty: u32, line: 3
+naked: true
+---
+trait Shape {
+    type Output: Clone + Into<f64>;
+    const SIDES: u32;
+}
+impl Shape for Square {
+    type Output = f32;
+    <|>const SIDES: u32 = 4;
+}
+
+---
+span: [2, 4] => [2, 8]
+item: ImplItemType name: Item, trait_: Iterator, bounds: [], line: null, std_docs: "iter/trait.Iterator.html#associatedtype.Item"
+naked: true
+---
+impl Iterator for Counter {
+    <|>type Item = u32;
+}
+
+---
+span: [2, 4] => [2, 8]
+item: ImplItemType name: Target, trait_: Deref, bounds: [], line: null, std_docs: "ops/trait.Deref.html#associatedtype.Target"
+naked: true
+---
+impl std::ops::Deref for Wrapper {
+    <|>type Target = str;
+}
+
+---
+span: [2, 4] => [2, 8]
+item: ImplItemType name: Item, trait_: null, bounds: [], line: null, std_docs: null
+naked: true
+---
+impl Unknown for Foo {
+    <|>type Item = u32;
+}
+
+---
+span: [3, 4] => [3, 8]
+item: ImplItemType name: Target, trait_: Deref, bounds: [], line: null, std_docs: "ops/trait.Deref.html#associatedtype.Target"
+naked: true
+---
+trait Deref { type Target: Copy; }
+impl std::ops::Deref for Wrapper {
+    <|>type Target = str;
+}
@@ -1,17 +1,17 @@
 span: [1, 4] => [1, 13]
-item: PatStruct empty: false, bindings: Let
+item: PatStruct empty: false, bindings: Let, refutable: false
 ---
 let Foo<|> { x } = y;
 
 ---
 span: [1, 7] => [1, 13]
-item: PatTuple single_comma: false, bindings: Arg
+item: PatTuple single_comma: false, bindings: Arg, refutable: false
 ---
 fn foo((x, y)<|>: Type) {}
 
 ---
 span: [1, 4] => [1, 10]
-item: PatTuple single_comma: false, bindings: ForLoop
+item: PatTuple single_comma: false, bindings: ForLoop, refutable: false
 ---
 for (x, y)<|> in x {}
 
@@ -0,0 +1,72 @@
+span: [1, 0] => [1, 5]
+item: Local mutability: false, ident: x
+scope: [1, 22] => [3, 14]
+---
+let <|>x = String::new();
+let y = x.len();
+let x = y + 1;
+
+---
+span: [3, 4] => [3, 9]
+item: Local mutability: false, ident: t
+scope: [3, 14] => [4, 1]
+---
+let s = 1;
+{
+    let <|>t = s;
+}
+
+---
+span: [1, 0] => [1, 9]
+item: Local mutability: true, ident: t
+scope: null
+---
+let <|>mut t = 1;
+
+---
+span: [1, 7] => [1, 8]
+item: PatIdentScope ident: v, of: Fn, shadowed: false
+scope: [1, 28] => [3, 1]
+naked: true
+---
+fn len(<|>v: Vec<u8>) -> usize {
+    v.len()
+}
+
+---
+span: [2, 4] => [2, 9]
+item: PatIdentScope ident: large, of: Arm, shadowed: false
+scope: [2, 10] => [2, 31]
+---
+match n {
+    <|>large if large > 9 => large,
+    _ => 0,
+};
+
+---
+span: [1, 7] => [1, 8]
+item: PatIdentScope ident: v, of: IfLet, shadowed: false
+scope: [1, 23] => [3, 1]
+---
+if let <|>v @ Some(_) = o {
+    v
+} else {
+    0
+};
+
+---
+span: [1, 9] => [1, 12]
+item: PatIdentScope ident: arg, of: Closure, shadowed: false
+scope: [1, 18] => [1, 25]
+---
+let f = |a<|>rg: u8| arg + 1;
+
+---
+span: [1, 15] => [1, 21]
+item: PatTuple bindings: null, single_comma: false, default_binding: null, refutable: false
+scope: [1, 35] => [3, 18]
+---
+while let Some((a, <|>b)) = it.next() {
+    let c = a + b;
+    let b = c * 2;
+}
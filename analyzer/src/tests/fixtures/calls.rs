@@ -0,0 +1,51 @@
+span: [2, 0] => [2, 11]
+item: ExprCall of: TupleStruct, name: Point, parent: null, in_file: true
+---
+struct Point(i32, i32);
+Poi<|>nt(1, 2);
+---
+span: [1, 0] => [1, 7]
+item: ExprCall of: TupleVariant, name: Some, parent: Option, in_file: false
+---
+So<|>me(3);
+---
+span: [2, 0] => [2, 18]
+item: ExprCall of: TupleVariant, name: Circle, parent: Shape, in_file: true
+---
+enum Shape { Circle(f32) }
+Shape::Cir<|>cle(1.0);
+---
+span: [1, 0] => [1, 10]
+item: ExprCall of: AssociatedFunction, name: new, parent: Vec, in_file: false
+---
+Vec::n<|>ew();
+---
+span: [2, 0] => [2, 6]
+item: ExprCall of: Function, name: area, parent: null, in_file: true
+---
+fn area() {}
+ar<|>ea();
+---
+span: [1, 0] => [1, 30]
+item: ExprCall of: Function, name: "std::mem::swap", parent: null, in_file: false
+---
+std::mem::sw<|>ap(&mut a, &mut b);
+---
+span: [2, 0] => [2, 4]
+item: ExprCall of: Closure, name: c, parent: null, in_file: true
+---
+let c = |x: u8| x + 1;
+<|>c(1);
+---
+span: [2, 2] => [2, 7]
+item: ExprCall of: FnPointer, name: cb, parent: null, in_file: true
+naked: true
+---
+fn main(cb: fn(u8) -> u8) {
+  c<|>b(2);
+}
+---
+span: [1, 0] => [1, 6]
+item: ExprCall of: Expression, name: "(c)", parent: null, in_file: false
+---
+(c)<|>(3);
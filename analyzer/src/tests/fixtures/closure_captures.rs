@@ -0,0 +1,81 @@
+span: [2, 12] => [2, 13]
+item: ExprClosureArguments by_ref: [name], by_unknown: [], by_mut: [], by_move: [], fn_trait: {trait_: Fn, uncertain: false}
+scope: null
+---
+let name = String::from("x");
+let print = |<|>x: u8| println!("{} {}", name, x);
+
+---
+span: [2, 14] => [2, 15]
+item: ExprClosureArguments by_ref: [], by_unknown: [], by_mut: [count], by_move: [], fn_trait: {trait_: FnMut, uncertain: false}
+---
+let mut count = 0;
+let mut inc = <|>|| count += 1;
+
+---
+span: [3, 14] => [3, 15]
+item: ExprClosureArguments by_ref: [limit], by_unknown: [], by_mut: [], by_move: [data], fn_trait: {trait_: FnOnce, uncertain: false}
+---
+let data = vec![1, 2];
+let limit = 10;
+let consume = <|>|| { let total = limit; drop(data); total };
+
+---
+span: [3, 11] => [3, 15]
+item: ExprClosureMove by_move: [count, name], fn_trait: {trait_: FnMut, uncertain: true}
+---
+let name = String::new();
+let mut count = 0;
+let both = mo<|>ve || { count += 1; name.len() };
+
+---
+span: [2, 13] => [2, 14]
+item: ExprClosureArguments by_ref: [], by_unknown: [], by_mut: [], by_move: [], fn_trait: {trait_: Fn, uncertain: false}
+---
+let a = 1;
+let shadow = <|>|a: u8| a + 1;
+
+---
+span: [3, 11] => [3, 12]
+item: ExprClosureArguments by_ref: [], by_unknown: [], by_mut: [stack], by_move: [], fn_trait: {trait_: FnMut, uncertain: false}
+---
+let mut stack = Stack::new();
+impl Stack { fn push(&mut self, value: u8) {} }
+let push = |<|>value| stack.push(value);
+
+---
+span: [2, 8] => [2, 9]
+item: ExprClosureArguments by_ref: [x], by_unknown: [], by_mut: [], by_move: [], fn_trait: {trait_: Fn, uncertain: false}
+---
+let x = 1;
+let f = <|>|| { let y = x + 1; let x = 5; y + x };
+
+---
+span: [2, 11] => [2, 12]
+item: ExprClosureArguments by_ref: [], by_unknown: [v], by_mut: [], by_move: [], fn_trait: {trait_: Fn, uncertain: true}
+---
+let mut v = vec![];
+let push = <|>|| v.push(1);
+
+---
+span: [3, 8] => [3, 9]
+item: ExprClosureArguments by_ref: [p], by_unknown: [], by_mut: [], by_move: [], fn_trait: {trait_: Fn, uncertain: false}
+---
+struct Point { x: i32 }
+let p = Point { x: 1 };
+let f = <|>|| p.x;
+
+---
+span: [3, 8] => [3, 9]
+item: ExprClosureArguments by_ref: [], by_unknown: [], by_mut: [], by_move: [n], fn_trait: {trait_: FnOnce, uncertain: false}
+---
+struct Named(String, u8);
+let n = Named(String::new(), 1);
+let f = <|>|| n.0;
+
+---
+span: [2, 8] => [2, 9]
+item: ExprClosureArguments by_ref: [], by_unknown: [t], by_mut: [], by_move: [], fn_trait: {trait_: Fn, uncertain: true}
+---
+let t = (String::new(), 1);
+let f = <|>|| t.0;
@@ -0,0 +1,127 @@
+span: [1, 0] => [1, 5]
+item: ItemConst value: "4096", unevaluated: null, visibility: {path: "crate::SIZE", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const SIZE: usize = 4 * 1024;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: "240", unevaluated: null, visibility: {path: "crate::MASK", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const MASK: u8 = !0 << 4;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: null, unevaluated: Overflow, visibility: {path: "crate::TOO_LARGE", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const TOO_LARGE: u8 = 200 + 100;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: null, unevaluated: Cycle, visibility: {path: "crate::A", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const A: usize = B + 1;
+const B: usize = A * 2;
+
+---
+span: [3, 12] => [3, 27]
+item: ExprRepeat len: SIZE / 2, value: "2048", unevaluated: null
+naked: true
+---
+const SIZE: usize = 4 * 1024;
+fn main() {
+    let a = <|>[0u8; SIZE / 2];
+}
+
+---
+span: [1, 8] => [1, 33]
+item: ExprRepeat len: u8::MAX as usize + 1, value: "256", unevaluated: null
+---
+let a = <|>[0; u8::MAX as usize + 1];
+
+---
+span: [1, 8] => [1, 19]
+item: ExprRepeat len: 10 / 0, value: null, unevaluated: DivisionByZero
+---
+let a = <|>[0; 10 / 0];
+
+---
+span: [1, 28] => [1, 39]
+item: TypeArray len: N * 2, value: null, unevaluated: Generic
+naked: true
+---
+struct Pair<const N: usize>(<|>[u8; N * 2]);
+
+---
+span: [1, 7] => [1, 19]
+item: TypeArray len: 1 << 3, value: "8", unevaluated: null
+---
+let b: <|>[u8; 1 << 3] = [0; 8];
+
+---
+span: [3, 18] => [3, 30]
+item: GenericArgumentConst value: "4097", unevaluated: null
+naked: true
+---
+const SIZE: usize = 4 * 1024;
+fn main() {
+    let c = Foo::<<|>{ SIZE + 1 }>::new();
+}
+
+---
+span: [1, 14] => [1, 23]
+item: GenericArgumentConst value: null, unevaluated: Unsupported
+---
+let c = Foo::<<|>{ len() }>::new();
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: null, unevaluated: Overflow, visibility: {path: "crate::NEGATED", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const NEGATED: i128 = -i128::MIN;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: null, unevaluated: Unsupported, visibility: {path: "crate::ALL", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const ALL: u128 = u128::MAX;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: null, unevaluated: Unsupported, visibility: {path: "crate::ONES", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const ONES: u128 = !0u128;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: null, unevaluated: Unsupported, visibility: {path: "crate::TOP", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const TOP: usize = (u128::MAX >> 120) as usize;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: null, unevaluated: Unsupported, visibility: {path: "crate::HIGH", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const HIGH: u128 = 1 << 127;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: null, unevaluated: Unsupported, visibility: {path: "crate::BIG", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const BIG: u128 = 340282366920938463463374607431768211455 - 1;
+
+---
+span: [1, 0] => [1, 5]
+item: ItemConst value: "170141183460469231731687303715884105726", unevaluated: null, visibility: {path: "crate::HALF", reachable: crate, limited_by: null}
+naked: true
+---
+<|>const HALF: u128 = i128::MAX as u128 - 1;
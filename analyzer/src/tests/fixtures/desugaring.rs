@@ -0,0 +1,91 @@
+span: [1, 21] => [1, 22]
+item: ExprTryQuestionMark of: Function, returns: Unit, error: null, main: false
+desugared: "match Try::branch(read(path)) {\n    ControlFlow::Continue(value) => value,\n    ControlFlow::Break(residual) => return FromResidual::from_residual(residual),\n}"
+---
+let data = read(path)?<|>;
+
+---
+span: [1, 0] => [1, 3]
+item: ExprForLoopToken
+desugared: "{\n    let mut iter = IntoIterator::into_iter(items);\n    loop {\n        match Iterator::next(&mut iter) {\n            Some(item) => {\n                total += item;\n            },\n            None => break,\n        }\n    }\n}"
+---
+f<|>or item in items { total += item; }
+
+---
+span: [1, 8] => [1, 17]
+item: ExprWhileLet
+desugared: "'outer: loop {\n    match stack.pop() {\n        Some(top) => {\n            visit(top);\n        },\n        _ => break,\n    }\n}"
+---
+'outer: wh<|>ile let Some(top) = stack.pop() { visit(top); }
+
+---
+span: [1, 0] => [1, 5]
+item: ExprWhile
+desugared: null
+---
+wh<|>ile running { step(); }
+
+---
+span: [1, 0] => [1, 5]
+item: AsyncFn
+naked: true
+desugared: "fn fetch(url: &str) -> impl Future<Output = Vec<u8>> {\n    async move {\n        get(url).await\n    }\n}"
+---
+as<|>ync fn fetch(url: &str) -> Vec<u8> { get(url).await }
+
+---
+span: [1, 11] => [1, 15]
+item: TypeImplTrait
+naked: true
+desugared: "fn show<T: Display, U: Iterator<Item = u8>>(a: T, b: &mut U);"
+---
+fn show(a: im<|>pl Display, b: &mut impl Iterator<Item = u8>) {}
+
+---
+span: [1, 16] => [1, 20]
+item: TypeImplTrait
+naked: true
+desugared: null
+---
+fn numbers() -> im<|>pl Iterator<Item = u8> {}
+
+---
+span: [1, 2] => [1, 4]
+item: AddEqBinOp
+desugared: "AddAssign::add_assign(&mut x, 1)"
+---
+x +<|>= 1;
+
+---
+span: [1, 8] => [1, 13]
+item: ExprRangeClosed from: true, to: true
+desugared: "RangeInclusive::new(1, n)"
+---
+let r = 1.<|>.=n;
+
+---
+span: [1, 8] => [1, 11]
+item: ExprRangeHalfOpen from: false, to: true
+desugared: "RangeTo { end: n }"
+---
+let r = .<|>.n;
+
+---
+span: [3, 26] => [3, 32]
+item: ExprStructRest
+naked: true
+desugared: "Point {\n    x: 1,\n    y: base.y,\n    z: base.z,\n}"
+---
+struct Point { x: u8, y: u8, z: u8 }
+fn main() {
+    let p = Point { x: 1, .<|>.base };
+}
+
+---
+span: [1, 22] => [1, 30]
+item: ExprStructRest
+desugared: "{\n    let base = make();\n    Point {\n        y: 2,\n        x: base.x,\n        z: base.z,\n    }\n}"
+naked: false
+---
+let q = Point { y: 2, .<|>.make() };
+struct Point { x: u8, y: u8, z: u8 }
@@ -0,0 +1,50 @@
+span: [6, 0] => [6, 1]
+item: Block locals: [guard, b, a, x], args: []
+naked: true
+---
+fn main() {
+    let x = Lock::new();
+    let (a, b) = pair();
+    let _ = x.lock();
+    let guard = x.lock();
+<|>}
+
+---
+span: [2, 44] => [2, 45]
+item: Block locals: [data], args: [rest, first, self]
+naked: true
+---
+impl Conn {
+    fn close(self, (first, rest): (u8, u8)) <|>{
+        let data = first + rest;
+    }
+}
+
+---
+span: [1, 0] => [1, 1]
+item: Block locals: [], args: []
+---
+<|>{
+    drop(value);
+}
+
+---
+span: [1, 0] => [1, 9]
+item: ItemStruct name: Tx, unit: false, generic: true, fields: [guard, conn], drop_impl: true, visibility: {path: "crate::Tx", reachable: crate, limited_by: null}
+naked: true
+---
+struct <|>Tx<'a> {
+    guard: Guard,
+    conn: &'a mut Conn,
+}
+
+impl Drop for Tx<'_> {
+    fn drop(&mut self) {}
+}
+
+---
+span: [1, 0] => [1, 11]
+item: ItemStruct name: Pair, unit: false, generic: false, fields: ["0", "1"], drop_impl: false, visibility: {path: "crate::Pair", reachable: crate, limited_by: null}
+naked: true
+---
+struct <|>Pair(A, B);
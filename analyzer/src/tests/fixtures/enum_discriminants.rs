@@ -0,0 +1,81 @@
+span: [3, 0] => [3, 11]
+item: ItemEnum empty: false, generic: false, discriminants: [Nop = 0, Load = 16, Store = 17, Jump = 128, Halt = 255, Overflow = ?], visibility: {path: "crate::Opcode", reachable: crate, limited_by: null}
+naked: true
+---
+const BASE: u8 = 0x10;
+#[repr(u8)]
+<|>enum Opcode {
+    Nop,
+    Load = BASE,
+    Store,
+    Jump = 1 << 7,
+    Halt = 255,
+    Overflow,
+}
+
+---
+span: [6, 4] => [6, 9]
+item: Variant name: Opcode, discriminant: "17", unevaluated: null
+naked: true
+---
+const BASE: u8 = 0x10;
+#[repr(u8)]
+enum Opcode {
+    Nop,
+    Load = BASE,
+    <|>Store,
+}
+
+---
+span: [4, 9] => [4, 17]
+item: VariantDiscriminant name: Jump, value: "128", unevaluated: null
+naked: true
+---
+#[repr(u8)]
+enum Opcode {
+    Nop,
+    Jump <|>= 1 << 7,
+}
+
+---
+span: [4, 4] => [4, 12]
+item: Variant name: Opcode, discriminant: null, unevaluated: Overflow
+naked: true
+---
+#[repr(u8)]
+enum Opcode {
+    Halt = 255,
+    <|>Overflow,
+}
+
+---
+span: [3, 4] => [3, 9]
+item: Variant name: Ordering, discriminant: "0", unevaluated: null
+naked: true
+---
+enum Ordering {
+    Less = -1,
+    <|>Equal,
+    Greater,
+}
+
+---
+span: [1, 0] => [1, 10]
+item: ItemEnum empty: false, generic: false, discriminants: [], visibility: {path: "crate::Shape", reachable: crate, limited_by: null}
+naked: true
+---
+<|>enum Shape {
+    Circle(f32),
+    Square,
+}
+
+---
+span: [4, 4] => [4, 9]
+item: Variant name: Wide, discriminant: null, unevaluated: Overflow
+naked: true
+---
+#[repr(i128)]
+enum Wide {
+    Last = i128::MAX,
+    <|>After,
+}
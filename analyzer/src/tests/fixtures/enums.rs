@@ -1,5 +1,5 @@
 span: [4, 6] => [4, 7]
-item: Variant name: Foo
+item: Variant name: Foo, discriminant: "0"
 ---
 enum Bar {
   A = {
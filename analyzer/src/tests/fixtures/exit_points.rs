@@ -0,0 +1,101 @@
+span: [1, 0] => [1, 8]
+item: ItemFn visibility: {path: "crate::parse", reachable: crate, limited_by: null}
+naked: true
+exit: [2, 23] => [2, 24] ?
+exit: [4, 8] => [4, 25] return
+exit: [7, 8] => [7, 34] panic!
+exit: [10, 4] => [10, 12] tail expression
+---
+<|>fn parse(input: &str) -> Result<u8, Error> {
+    let n = read(input)?;
+    if n == 0 {
+        return Err(Empty);
+    }
+    if n > 100 {
+        panic!("too large: {}", n);
+    }
+    let f = |x: u8| -> Option<u8> { Some(x.checked_add(1)?) };
+    Ok(f(n))
+}
+
+---
+span: [1, 26] => [1, 28]
+item: RArrow return_of: Function
+naked: true
+exit: [3, 8] => [3, 32] process::exit
+exit: [5, 4] => [5, 27] unreachable!
+---
+fn run(code: Option<i32>) <|>-> ! {
+    if let Some(code) = code {
+        std::process::exit(code);
+    }
+    unreachable!("no code")
+}
+
+---
+span: [2, 4] => [2, 10]
+item: ImplItemMethod of: Method, trait_: null, self_ty: Logger
+naked: true
+exit: [4, 12] => [4, 18] return
+exit: [8, 4] => [8, 5] end of body
+---
+impl Logger {
+    <|>fn log(&mut self, line: String) {
+        if line.is_empty() {
+            return;
+        }
+        let task = async move { write(line).await? };
+        self.tasks.push(task);
+    }
+}
+
+---
+span: [1, 0] => [1, 8]
+item: ItemFn visibility: {path: "crate::serve", reachable: crate, limited_by: null}
+naked: true
+exit: [2, 25] => [2, 26] ?
+exit: [4, 16] => [4, 17] ?
+---
+<|>fn serve() -> Result<(), Error> {
+    println!("{}", bind()?);
+    loop {
+        accept()?;
+    }
+}
+
+---
+span: [1, 10] => [1, 12]
+item: RArrow return_of: Function
+naked: true
+exit: [2, 4] => [2, 25] process::exit
+---
+fn quit() <|>-> ! {
+    std::process::exit(1)
+}
+
+---
+span: [1, 0] => [1, 8]
+item: ItemFn visibility: {path: "crate::check", reachable: crate, limited_by: null}
+naked: true
+exit: [3, 8] => [3, 16] return
+exit: [5, 4] => [5, 25] process::exit
+---
+<|>fn check(ok: bool) -> i32 {
+    if ok {
+        return 1;
+    }
+    std::process::exit(1);
+}
+
+---
+span: [1, 0] => [1, 7]
+item: ItemFn visibility: {path: "crate::spin", reachable: crate, limited_by: null}
+naked: true
+exit: [3, 8] => [3, 14] return
+---
+<|>fn spin(done: bool) {
+    if done {
+        return;
+    }
+    loop {}
+}
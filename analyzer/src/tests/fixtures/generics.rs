@@ -1,5 +1,5 @@
 span: [1, 0] => [1, 10]
-item: ItemStruct name: Foo, unit: false, generic: true
+item: ItemStruct name: Foo, unit: false, generic: true, fields: [x], drop_impl: false
 ---
 struct <|>Foo<T> {
   x: T
@@ -0,0 +1,83 @@
+span: [2, 0] => [2, 4]
+item: ItemImpl trait_: null, negative: false, self_ty: Point, generic: false, blanket: false, local_trait: false, local_type: true
+naked: true
+---
+struct Point;
+<|>impl Point {}
+
+---
+span: [1, 0] => [1, 4]
+item: ItemImpl trait_: null, negative: false, self_ty: "Vec<u8>", generic: false, blanket: false, local_trait: false, local_type: false
+naked: true
+---
+<|>impl Vec<u8> {}
+
+---
+span: [2, 0] => [2, 4]
+item: ItemImpl trait_: Shape, negative: false, self_ty: "Vec<T>", generic: true, blanket: false, local_trait: true, local_type: false
+naked: true
+---
+trait Shape {}
+<|>impl<T> Shape for Vec<T> {}
+
+---
+span: [2, 0] => [2, 4]
+item: ItemImpl trait_: Describe, negative: false, self_ty: T, generic: true, blanket: true, local_trait: true, local_type: false
+naked: true
+---
+trait Describe {}
+<|>impl<T: Display> Describe for T {}
+
+---
+span: [2, 0] => [2, 4]
+item: ItemImpl trait_: "fmt::Display", negative: false, self_ty: "&Point", generic: false, blanket: false, local_trait: false, local_type: true
+naked: true
+---
+struct Point;
+<|>impl fmt::Display for &Point {}
+
+---
+span: [2, 0] => [2, 4]
+item: ItemImpl trait_: "From<Point>", negative: false, self_ty: String, generic: false, blanket: false, local_trait: false, local_type: true
+naked: true
+---
+struct Point;
+<|>impl From<Point> for String {}
+
+---
+span: [1, 0] => [1, 4]
+item: ItemImpl trait_: Display, negative: false, self_ty: "Vec<u8>", generic: false, blanket: false, local_trait: false, local_type: false
+naked: true
+---
+<|>impl Display for Vec<u8> {}
+
+---
+span: [2, 5] => [2, 6]
+item: ItemImplNegative trait_: Send, self_ty: Token
+naked: true
+---
+struct Token;
+impl <|>!Send for Token {}
+
+---
+span: [2, 0] => [2, 4]
+item: ItemImpl trait_: Display, negative: false, self_ty: "Box<Point>", generic: false, blanket: false, local_trait: false, local_type: true
+naked: true
+---
+struct Point;
+<|>impl Display for Box<Point> {}
+
+---
+span: [2, 0] => [2, 4]
+item: ItemImpl trait_: "From<Pin<&mut Point>>", negative: false, self_ty: String, generic: false, blanket: false, local_trait: false, local_type: true
+naked: true
+---
+struct Point;
+<|>impl From<Pin<&mut Point>> for String {}
+
+---
+span: [1, 0] => [1, 4]
+item: ItemImpl trait_: Display, negative: false, self_ty: T, generic: true, blanket: true, local_trait: false, local_type: false
+naked: true
+---
+<|>impl<T> Display for T {}
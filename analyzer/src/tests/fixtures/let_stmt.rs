@@ -23,6 +23,6 @@ le<|>t x: i32;
 
 ---
 span: [1, 4] => [1, 10]
-item: PatTuple bindings: Let, single_comma: false
+item: PatTuple bindings: Let, single_comma: false, refutable: false
 ---
 let <|>(x, y): (A, B);
\ No newline at end of file
@@ -0,0 +1,68 @@
+span: [1, 21] => [1, 25]
+item: ElidedLifetime ty: "&str", source: OnlyInput, input: s, lifetime: "'a", annotated: "fn first<'a>(s: &'a str) -> &'a str"
+naked: true
+---
+fn first(s: &str) -> &<|>str {}
+
+---
+span: [2, 42] => [2, 46]
+item: ElidedLifetime ty: "&str", source: SelfRef, input: self, lifetime: "'a", annotated: "fn name<'a, 'b>(&'a self, other: &'b str) -> Option<&'a str>"
+naked: true
+---
+impl S {
+    fn name(&self, other: &str) -> Option<&<|>str> {}
+}
+
+---
+span: [1, 32] => [1, 36]
+item: ElidedLifetime ty: "&str", source: Ambiguous, input: null, lifetime: null, annotated: null
+naked: true
+---
+fn longest(x: &str, y: &str) -> &<|>str {}
+
+---
+span: [1, 13] => [1, 17]
+item: ElidedLifetime ty: "&str", source: NoInputs, input: null, lifetime: null, annotated: null
+naked: true
+---
+fn none() -> &<|>str {}
+
+---
+span: [1, 44] => [1, 49]
+item: ElidedLifetime ty: "&'_ T", source: OnlyInput, input: x, lifetime: "'a", annotated: "fn g<'a, T>(x: &'a T, f: fn(&u8) -> &u8) -> &'a T"
+naked: true
+---
+fn g<'a, T>(x: &'a T, f: fn(&u8) -> &u8) -> &<|>'_ T {}
+
+---
+span: [1, 19] => [1, 22]
+item: TypeReference mutable: false, lifetime: false, ty: u8
+naked: true
+---
+fn g(f: fn(&u8) -> <|>&u8) {}
+
+---
+span: [1, 42] => [1, 46]
+item: ElidedLifetime ty: "&str", source: OnlyInput, input: null, lifetime: "'a", annotated: "fn longest<'a>(x: &'a str, y: &'a str) -> &'a str"
+naked: true
+---
+fn longest<'a>(x: &'a str, y: &'a str) -> &<|>str {}
+
+---
+span: [1, 27] => [1, 29]
+item: ElidedLifetime ty: "Iter<'_, u8>", source: OnlyInput, input: v, lifetime: "'a", annotated: "fn items<'a>(v: &'a [u8]) -> Iter<'a, u8>"
+naked: true
+---
+fn items(v: &[u8]) -> Iter<'<|>_, u8> {}
+---
+span: [1, 18] => [1, 23]
+item: ElidedLifetime ty: "&[u8]", source: OnlyInput, input: x, lifetime: "'a", annotated: "fn f<'a>(x: &'a [u8]) -> &'a [u8]"
+naked: true
+---
+fn f(x: &[u8]) -> <|>&[u8] {}
+---
+span: [1, 22] => [1, 31]
+item: ElidedLifetime ty: "&(u8, u8)", source: OnlyInput, input: x, lifetime: "'a", annotated: "fn f<'a>(x: &'a (u8, u8)) -> &'a (u8, u8)"
+naked: true
+---
+fn f(x: &(u8, u8)) -> <|>&(u8, u8) {}
@@ -0,0 +1,60 @@
+span: [3, 0] => [3, 5]
+item: ExprMatchToken enum_: Shape, uncovered: [Square], catch_all: false, guard: true
+naked: true
+---
+enum Shape { Circle(f32), Square { side: f32 }, Dot }
+fn f(s: Shape) {
+ma<|>tch s {
+    Shape::Circle(_) | Shape::Dot => {}
+    Shape::Square { side } if side > 1.0 => {}
+}
+}
+---
+span: [3, 0] => [3, 5]
+item: ExprMatchToken enum_: Shape, uncovered: [Square, Dot], catch_all: true, guard: false
+naked: true
+---
+enum Shape { Circle(f32), Square { side: f32 }, Dot }
+fn f(s: Shape) {
+<|>match s {
+    Shape::Circle(r) => {}
+    _ => {}
+}
+}
+---
+span: [4, 0] => [4, 5]
+item: ExprMatchToken enum_: Shape, uncovered: [Circle], catch_all: true, guard: false
+naked: true
+---
+enum Shape { Circle(f32), Square { side: f32 }, Dot }
+use Shape::*;
+fn f(s: &Shape) {
+match<|> s {
+    Circle(0.0) => {}
+    Square { .. } => {}
+    Dot => {}
+    other => {}
+}
+}
+---
+span: [4, 8] => [4, 13]
+item: ExprMatchToken enum_: Light, uncovered: [], catch_all: false, guard: false
+naked: true
+---
+enum Light { On, Off }
+impl Light {
+    fn toggle(self) -> Self {
+        mat<|>ch self {
+            Self::On => Self::Off,
+            Self::Off => Self::On,
+        }
+    }
+}
+---
+span: [1, 0] => [1, 5]
+item: ExprMatchToken enum_: null, uncovered: [], catch_all: false, guard: false
+---
+mat<|>ch opt {
+    Some(x) => {}
+    None => {}
+}
@@ -0,0 +1,72 @@
+span: [2, 9] => [2, 10]
+item: PatIdent mutability: false, by_ref: false, ident: x, default_binding: Ref
+---
+match &opt {
+    Some(<|>x) => {}
+    None => {}
+}
+---
+span: [2, 4] => [2, 11]
+item: PatTupleStruct bindings: null, default_binding: Ref, refutable: true
+---
+match &opt {
+    So<|>me(x) => {}
+    None => {}
+}
+---
+span: [1, 5] => [1, 6]
+item: PatIdent mutability: false, by_ref: false, ident: k, default_binding: Ref
+---
+for (<|>k, v) in &map {}
+---
+span: [1, 5] => [1, 6]
+item: PatIdent mutability: false, by_ref: false, ident: i, default_binding: RefMut
+---
+for (<|>i, x) in &mut v {}
+---
+span: [1, 4] => [1, 10]
+item: PatTuple bindings: ForLoop, single_comma: false, default_binding: null, refutable: false
+---
+for <|>(k, v) in map.iter() {}
+---
+span: [2, 5] => [2, 6]
+item: PatIdent mutability: false, by_ref: false, ident: a, default_binding: RefMut
+---
+let pair = &mut (1, 2);
+let (<|>a, b) = pair;
+---
+span: [1, 6] => [1, 7]
+item: PatIdent mutability: false, by_ref: false, ident: a, default_binding: Ref
+naked: true
+---
+fn f((<|>a, b): &(u8, u8)) {}
+---
+span: [3, 36] => [3, 37]
+item: PatIdent mutability: false, by_ref: false, ident: n, default_binding: Ref
+naked: true
+---
+enum E { A(u8) }
+impl E {
+    fn f(&self) { match self { E::A(<|>n) => {} } }
+}
+---
+span: [1, 11] => [1, 12]
+item: PatIdent mutability: false, by_ref: false, ident: x, default_binding: Ref
+---
+if let P { <|>x } = &p {}
+---
+span: [2, 4] => [2, 11]
+item: PatTupleStruct bindings: null, default_binding: null, refutable: true
+---
+match opt {
+    Some(<|>x) => {}
+    None => {}
+}
+---
+span: [2, 9] => [2, 11]
+item: PatReference mutability: false, refutable: false
+---
+match &opt {
+    Some(&<|>x) => {}
+    _ => {}
+}
@@ -0,0 +1,73 @@
+span: [4, 20] => [4, 24]
+item: ExprMethodCall method: get, self_ty: Foo, trait_: null, receiver: Ref, line: 2
+naked: true
+---
+struct Foo;
+impl Foo { fn get(&self) {} }
+impl Bar { fn get(&mut self) {} }
+fn f(foo: Foo) { foo<|>.get(); }
+---
+span: [4, 23] => [4, 27]
+item: ExprMethodCall method: get, self_ty: Bar, trait_: null, receiver: RefMut, line: 3
+naked: true
+---
+struct Bar;
+impl Foo { fn get(&self) {} }
+impl Bar { fn get(&mut self) {} }
+fn f() { let b = Bar; b.g<|>et(); }
+---
+span: [3, 12] => [3, 17]
+item: ExprMethodCall method: take, self_ty: Foo, trait_: null, receiver: Value, line: 2
+naked: true
+---
+struct Foo;
+impl Foo { fn take(self) {} }
+fn f() { Foo.t<|>ake(); }
+---
+span: [3, 16] => [3, 21]
+item: ExprMethodCall method: show, self_ty: null, trait_: Show, receiver: Ref, line: 1
+naked: true
+---
+trait Show { fn show(&self) {} }
+impl Show for u8 {}
+fn f(x: &u8) { x.<|>show(); }
+---
+span: [4, 7] => [4, 13]
+item: ExprMethodCall method: boxed, self_ty: Foo, trait_: null, receiver: Special, line: 2
+naked: true
+---
+struct Foo;
+impl Foo { fn boxed(self: Box<Self>) {} }
+fn f(foo: Box<Foo>) {
+    foo.box<|>ed();
+}
+---
+span: [1, 1] => [1, 5]
+item: ExprMethodCall method: len, self_ty: null, trait_: null, receiver: null, line: null
+---
+v.l<|>en();
+---
+span: [3, 16] => [3, 20]
+item: ExprMethodCall method: len, self_ty: null, trait_: null, receiver: null, line: null
+naked: true
+---
+struct Foo;
+impl Foo { fn len(&mut self) -> usize { 0 } }
+fn f() { vec![1].le<|>n(); }
+---
+span: [3, 19] => [3, 25]
+item: ExprMethodCall method: clone, self_ty: null, trait_: null, receiver: null, line: null
+naked: true
+---
+struct Foo;
+impl Clone for Foo { fn clone(&self) -> Self { Foo } }
+fn f(s: String) { s.cl<|>one(); }
+---
+span: [4, 26] => [4, 30]
+item: ExprMethodCall method: get, self_ty: "W<u16>", trait_: null, receiver: RefMut, line: 3
+naked: true
+---
+struct W<T>(T);
+impl W<u8> { fn get(&self) {} }
+impl W<u16> { fn get(&mut self) {} }
+fn f(w: crate::W<u16>) { w.g<|>et(); }
@@ -0,0 +1,83 @@
+span: [1, 15] => [1, 17]
+item: AsCast from: u16, to: u8, conversion: Truncate, result: "44"
+---
+let a = 300u16 <|>as u8;
+
+---
+span: [1, 14] => [1, 16]
+item: AsCast from: i32, to: u32, conversion: Reinterpret, result: "4294967295"
+---
+let b = -1i32 <|>as u32;
+
+---
+span: [1, 16] => [1, 18]
+item: AsCast from: f64, to: i32, conversion: FloatToInt, result: "2147483647"
+---
+let c = 1e10f64 <|>as i32;
+
+---
+span: [1, 20] => [1, 22]
+item: AsCast from: u32, to: f32, conversion: IntToFloat, result: "16777216.0"
+---
+let d = 16777217u32 <|>as f32;
+
+---
+span: [2, 10] => [2, 12]
+item: AsCast from: i8, to: i64, conversion: SignExtend, result: null
+---
+let x: i8 = -3;
+let i = x <|>as i64;
+
+---
+span: [1, 21] => [1, 23]
+item: AsCast from: u8, to: char, conversion: ByteToChar, result: "','"
+---
+let j = 300u16 as u8 <|>as char;
+
+---
+span: [1, 13] => [1, 15]
+item: AsCast from: bool, to: i32, conversion: BoolToInt, result: "1"
+---
+let g = true <|>as i32;
+
+---
+span: [2, 6] => [2, 8]
+item: AsCast from: "*const u8", to: usize, conversion: PointerToInt, result: null
+naked: true
+---
+fn address(p: *const u8) -> usize {
+    p <|>as usize
+}
+
+---
+span: [1, 13] => [1, 15]
+item: AsCast from: u32, to: usize, conversion: PointerSized, result: null
+---
+let k = 5u32 <|>as usize;
+
+---
+span: [1, 14] => [1, 16]
+item: AsCast from: u32, to: char, conversion: Invalid, result: null
+---
+let o = 65u32 <|>as char;
+
+---
+span: [1, 10] => [1, 12]
+item: AsCast from: null, to: u8, conversion: Unknown, result: null
+---
+let m = 3 <|>as u8;
+
+---
+span: [2, 6] => [2, 8]
+item: AsCast from: "*const u32", to: "*mut u8", conversion: PointerToPointer, result: null
+naked: true
+---
+fn bytes(p: *const u32) -> *mut u8 {
+    p <|>as *mut u8
+}
+
+---
+span: [1, 17] => [1, 19]
+item: AsCast from: f64, to: i128, conversion: FloatToInt, result: "-170141183460469231731687303715884105728"
+---
+let k = -1e40f64 <|>as i128;
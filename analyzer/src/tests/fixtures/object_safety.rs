@@ -0,0 +1,58 @@
+span: [4, 0] => [4, 12]
+item: ItemTrait generic: false, supertraits: [Named, Clone], safety: {safe: false, supertraits: [Clone], generic: [feed], self_: [replace], no_receiver: [create], consts: [LEGS], sized: false}, visibility: {path: "crate::Animal", reachable: crate, limited_by: null}
+naked: true
+---
+trait Named {
+    fn name(&self) -> String;
+}
+<|>trait Animal: Named + Clone {
+    const LEGS: u32;
+    fn speak(&self);
+    fn replace(&mut self, other: Self);
+    fn new() -> Self where Self: Sized;
+    fn feed<F: Food>(&mut self, food: F);
+    fn create() -> u32;
+}
+
+---
+span: [4, 11] => [4, 24]
+item: ItemTraitSupertraits supertraits: [Pet, Named, Sized], safety: {safe: false, supertraits: [], generic: [], self_: [], no_receiver: [], consts: [], sized: true}
+naked: true
+---
+trait Named {}
+trait Pet: Named + Sized {}
+trait Dog: Pet {}
+trait Puppy:<|> Pet + Named {}
+
+---
+span: [5, 9] => [5, 18]
+item: TypeTraitObject ty: Named, lifetime: null, multiple: false, dyn_: true, supertraits: [Display, Debug], safety: {safe: true, supertraits: [], generic: [], self_: [], no_receiver: [], consts: [], sized: false}
+naked: true
+---
+trait Named: Display + Debug {
+    fn name(&self) -> &Self::Name;
+    fn new(name: String) -> Self where Self: Sized;
+}
+fn f(a: &<|>dyn Named) {}
+
+---
+span: [1, 9] => [1, 28]
+item: TypeTraitObject ty: "std :: fmt :: Debug", lifetime: null, multiple: false, dyn_: true, supertraits: [], safety: null
+naked: true
+---
+fn f(a: &<|>dyn std::fmt::Debug) {}
+
+---
+span: [2, 9] => [2, 30]
+item: TypeTraitObject ty: "std :: error :: Error", lifetime: null, multiple: false, dyn_: true, supertraits: [Debug, Display], safety: null
+naked: true
+---
+trait Error { fn new() -> Self; }
+fn f(a: &<|>dyn std::error::Error) {}
+
+---
+span: [1, 12] => [1, 20]
+item: TypeTraitObject ty: Copy, lifetime: null, multiple: false, dyn_: true, supertraits: [Clone], safety: {safe: false, supertraits: [Clone], generic: [], self_: [], no_receiver: [], consts: [], sized: false}
+naked: true
+---
+fn f(a: Box<<|>dyn Copy>) {}
@@ -0,0 +1,56 @@
+span: [1, 10] => [1, 11]
+item: AddBinOp grouping: "a + (b * (c as u32))"
+operand: [1, 8] => [1, 9]
+operand: [1, 12] => [1, 24]
+---
+let x = a <|>+ b * c as u32;
+
+---
+span: [1, 14] => [1, 15]
+item: MulBinOp grouping: "a + (b * (c as u32))"
+operand: [1, 12] => [1, 13]
+operand: [1, 16] => [1, 24]
+---
+let x = a + b <|>* c as u32;
+
+---
+span: [1, 15] => [1, 16]
+item: SubBinOp grouping: "((-a) * b) - c"
+---
+let x = -a * b <|>- c;
+
+---
+span: [1, 10] => [1, 11]
+item: AddBinOp grouping: null
+---
+let x = a <|>+ b;
+
+---
+span: [1, 11] => [1, 12]
+item: AddBinOp grouping: null
+---
+let x = (a <|>+ b) * c;
+
+---
+span: [1, 10] => [1, 11]
+item: LtBinOp grouping: "(a < b) < c", chained: true
+---
+let x = a <|>< b < c;
+
+---
+span: [1, 15] => [1, 17]
+item: AndBinOp grouping: "(a == b) && (c == d)"
+---
+let x = a == b <|>&& c == d;
+
+---
+span: [1, 10] => [1, 12]
+item: EqBinOp grouping: "(a == b) && (c == d)", chained: false
+---
+let x = a <|>== b && c == d;
+
+---
+span: [1, 10] => [1, 11]
+item: MulBinOp grouping: "a * (-(c.d))"
+---
+let x = a <|>* -c.d;
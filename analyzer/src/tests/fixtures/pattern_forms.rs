@@ -0,0 +1,133 @@
+span: [2, 4] => [2, 21]
+item: PatSlice len: 2, rest: true, refutable: false
+---
+match v {
+    [fi<|>rst, .., last] => {}
+}
+---
+span: [2, 12] => [2, 14]
+item: PatSliceRest ident: null
+---
+match v {
+    [first, <|>.., last] => {}
+}
+---
+span: [2, 12] => [2, 21]
+item: PatSliceRest ident: rest
+---
+match v {
+    [first, rest <|>@ ..] => {}
+}
+---
+span: [1, 4] => [1, 10]
+item: PatSlice len: 2, rest: false, refutable: false
+---
+let <|>[a, b] = arr;
+---
+span: [1, 4] => [1, 6]
+item: PatReference mutability: false, refutable: false
+---
+for <|>&x in v.iter() {}
+---
+span: [1, 7] => [1, 13]
+item: PatReference mutability: true, refutable: false
+---
+if let &mut<|> q = r {}
+---
+span: [2, 4] => [2, 5]
+item: PatLit
+---
+match x {
+    <|>3 => {}
+    _ => {}
+}
+---
+span: [2, 4] => [2, 6]
+item: PatLit
+---
+match x {
+    -<|>1 => {}
+    _ => {}
+}
+---
+span: [3, 4] => [3, 9]
+item: PatPath name: LIMIT, of: Const, refutable: true
+naked: true
+---
+const LIMIT: u8 = 3;
+fn f(x: u8) { match x {
+    LI<|>MIT => {}
+    _ => {}
+}}
+---
+span: [1, 7] => [1, 11]
+item: PatPath name: None, of: UnitVariant, refutable: true
+---
+if let No<|>ne = opt {}
+---
+span: [2, 4] => [2, 18]
+item: PatPath name: Less, of: UnitVariant, refutable: true
+---
+match ord {
+    Ordering::Le<|>ss => {}
+    _ => {}
+}
+---
+span: [1, 7] => [1, 14]
+item: PatPath name: MAX, of: Const, refutable: true
+---
+if let u8::M<|>AX = x {}
+---
+span: [3, 4] => [3, 8]
+item: PatPath name: Unit, of: UnitStruct, refutable: false
+naked: true
+---
+struct Unit;
+fn f(u: Unit) { match u {
+    Un<|>it => {}
+}}
+---
+span: [3, 4] => [3, 14]
+item: PatPath name: RED, of: UnitVariant, refutable: true
+naked: true
+---
+enum Color { RED, GREEN }
+fn f(c: Color) { match c {
+    Color::R<|>ED => {}
+    _ => {}
+}}
+---
+span: [4, 4] => [4, 7]
+item: PatPath name: RED, of: UnitVariant, refutable: true
+naked: true
+---
+enum Color { RED, GREEN }
+use Color::*;
+fn f(c: Color) { match c {
+    RE<|>D => {}
+    _ => {}
+}}
+---
+span: [2, 4] => [2, 11]
+item: PatMacro
+---
+match x {
+    my_mac<|>!() => {}
+    _ => {}
+}
+---
+span: [1, 6] => [1, 7]
+item: PatType ident: x, of: Arg
+naked: true
+---
+fn f(x<|>: u8) {}
+---
+span: [1, 11] => [1, 12]
+item: PatType ident: null, of: ClosureArg
+---
+let c = |&y:<|> &u8| y;
+---
+span: [1, 5] => [1, 6]
+item: PatType ident: z, of: Let
+---
+let z:<|> u8 = 1;
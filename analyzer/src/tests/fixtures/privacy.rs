@@ -0,0 +1,119 @@
+span: [6, 9] => [6, 15]
+item: PrivacyViolation of: Item, name: helper, declared_in: "crate::net", visible_in: "crate::net", from: crate, fields: []
+naked: true
+---
+mod net {
+    fn helper() {}
+}
+
+fn main() {
+    net::hel<|>per();
+}
+
+---
+span: [9, 12] => [9, 21]
+item: PrivacyViolation of: Constructor, name: Conn, declared_in: "crate::net", visible_in: "crate::net", from: crate, fields: [fd]
+naked: true
+---
+mod net {
+    pub struct Conn {
+        fd: u32,
+        pub port: u16,
+    }
+}
+
+fn main() {
+    let c = net::Co<|>nn { fd: 1, port: 2 };
+}
+
+---
+span: [9, 6] => [9, 8]
+item: PrivacyViolation of: Field, name: fd, declared_in: "crate::net::Conn", visible_in: "crate::net", from: crate, fields: []
+naked: true
+---
+mod net {
+    pub struct Conn {
+        fd: u32,
+        pub port: u16,
+    }
+}
+
+fn read(c: net::Conn) {
+    c.f<|>d;
+}
+
+---
+span: [9, 20] => [9, 22]
+item: PrivacyViolation of: Field, name: fd, declared_in: "crate::net::Conn", visible_in: "crate::net", from: crate, fields: []
+naked: true
+---
+mod net {
+    pub struct Conn {
+        fd: u32,
+        pub port: u16,
+    }
+}
+
+fn read(c: net::Conn) {
+    let net::Conn { f<|>d, .. } = c;
+}
+
+---
+span: [5, 20] => [5, 26]
+item: PrivacyViolation of: Item, name: secret, declared_in: "crate::outer::inner", visible_in: "crate::outer::inner", from: "crate::outer", fields: []
+naked: true
+---
+mod outer {
+    mod inner {
+        pub(self) fn secret() {}
+    }
+    fn f() { inner::sec<|>ret(); }
+}
+
+---
+span: [9, 12] => [9, 26]
+item: ExprStruct
+naked: true
+---
+mod net {
+    pub struct Conn {
+        fd: u32,
+    }
+}
+
+fn main() {
+    struct Conn { fd: u32 }
+    let c = Co<|>nn { fd: 1 };
+}
+
+---
+span: [10, 16] => [10, 26]
+item: PrivacyViolation of: Constructor, name: Conn, declared_in: "crate::net", visible_in: "crate::net", from: "crate::client", fields: [fd]
+naked: true
+---
+mod net {
+    pub struct Conn {
+        fd: u32,
+    }
+}
+
+mod client {
+    use super::net::Conn as Connection;
+    fn open() {
+        let c = Conn<|>ection { fd: 1 };
+    }
+}
+
+---
+span: [7, 12] => [7, 26]
+item: ExprStruct
+naked: true
+---
+mod net {
+    pub struct Conn {
+        fd: u32,
+    }
+}
+fn main() {
+    let c = Co<|>nn { fd: 1 };
+}
@@ -0,0 +1,64 @@
+span: [2, 39] => [2, 40]
+item: ExprTryQuestionMark of: Function, returns: Result, error: "Box<dyn Error>", main: false
+naked: true
+---
+fn load(path: &str) -> Result<Config, Box<dyn Error>> {
+    let text = fs::read_to_string(path)?<|>;
+    Ok(parse(&text))
+}
+
+---
+span: [2, 14] => [2, 15]
+item: ExprTryQuestionMark of: Function, returns: Result, error: null, main: false
+naked: true
+---
+fn flush(out: &mut File) -> io::Result<()> {
+    out.sync()?<|>;
+    Ok(())
+}
+
+---
+span: [3, 35] => [3, 36]
+item: ExprTryQuestionMark of: Method, returns: Option, error: null, main: false
+naked: true
+---
+impl Stack {
+    fn second(&self) -> Option<&u8> {
+        let top = self.items.last()?<|>;
+        self.below(top)
+    }
+}
+
+---
+span: [2, 30] => [2, 31]
+item: ExprTryQuestionMark of: Function, returns: Unit, error: null, main: true
+naked: true
+---
+fn main() {
+    let n: u8 = args().parse()?<|>;
+}
+
+---
+span: [1, 32] => [1, 33]
+item: ExprTryQuestionMark of: Closure, returns: Unknown, error: null, main: false
+---
+let first = |v: &[u8]| v.first()?<|>.checked_add(1);
+
+---
+span: [2, 31] => [2, 32]
+item: ExprTryQuestionMark of: AsyncBlock, returns: Result, error: "Error", main: false
+naked: true
+---
+fn fetch(url: Url) -> impl Future<Output = Result<Body, Error>> {
+    async move { get(url).await?<|>.body() }
+}
+
+---
+span: [2, 18] => [2, 19]
+item: ExprTryQuestionMark of: Function, returns: Future, error: null, main: false
+naked: true
+---
+fn ready() -> impl Future<Output = u8> {
+    let n = read()?<|>;
+    future::ready(n)
+}
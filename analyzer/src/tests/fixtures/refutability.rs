@@ -0,0 +1,91 @@
+span: [1, 4] => [1, 11]
+item: PatRefutable of: Let, pattern: "Some(x)", scrutinee: opt
+---
+let Some<|>(x) = opt;
+---
+span: [1, 4] => [1, 8]
+item: PatRefutable of: Let, pattern: None, scrutinee: opt
+---
+let No<|>ne = opt;
+---
+span: [1, 4] => [1, 10]
+item: PatRefutable of: ForLoop, pattern: "(x, 1)", scrutinee: pairs
+---
+for (x, <|>1) in pairs {}
+---
+span: [1, 5] => [1, 10]
+item: PatRefutable of: Arg, pattern: "Ok(x)", scrutinee: null
+naked: true
+---
+fn f(Ok(<|>x): Result<u8, ()>) {}
+---
+span: [2, 19] => [2, 31]
+item: PatTupleStruct bindings: Let, default_binding: null, refutable: false
+naked: true
+---
+enum One { Only(u8) }
+fn f(o: One) { let One::Only(<|>x) = o; }
+---
+span: [2, 19] => [2, 28]
+item: PatRefutable of: Let, pattern: "Two::A(x)", scrutinee: t
+naked: true
+---
+enum Two { A(u8), B }
+fn f(t: Two) { let Two::A<|>(x) = t; }
+---
+span: [2, 25] => [2, 34]
+item: PatTupleStruct bindings: null, default_binding: null, refutable: true
+naked: true
+---
+enum Two { A(u8), B }
+fn f(t: Two) { match t { Tw<|>o::A(x) => {} Two::B => {} } }
+---
+span: [1, 4] => [1, 18]
+item: PatStruct empty: false, bindings: Let, default_binding: null, refutable: false
+---
+let Po<|>int { x, y } = p;
+---
+span: [2, 4] => [2, 10]
+item: PatTuple bindings: null, single_comma: false, default_binding: null, refutable: true
+---
+match pair {
+    (0, y<|>) => {}
+    _ => {}
+}
+---
+span: [2, 4] => [2, 22]
+item: PatSlice len: 1, rest: true, default_binding: null, refutable: false
+---
+match v {
+    [first<|>, rest @ ..] => {}
+    [] => {}
+}
+---
+span: [1, 10] => [1, 11]
+item: PatOr
+---
+let Ok(x) <|>| Err(x) = r;
+---
+span: [2, 29] => [2, 30]
+item: PatOr
+naked: true
+---
+enum Two { A(u8), B(u8) }
+fn f(t: Two) { let Two::A(x) <|>| Two::B(x) = t; }
+---
+span: [1, 0] => [1, 5]
+item: Local ident: X, mutability: false
+---
+let <|>X = 5;
+---
+span: [2, 13] => [2, 18]
+item: PatRefutable of: Let, pattern: LIMIT, scrutinee: "4"
+naked: true
+---
+const LIMIT: u8 = 3;
+fn f() { let LI<|>MIT = 4; }
+---
+span: [1, 4] => [1, 9]
+item: PatRefutable of: Let, pattern: "Ok(v)", scrutinee: r
+---
+let Ok<|>(v) = r;
@@ -0,0 +1,74 @@
+span: [8, 11] => [8, 14]
+item: ItemImplForTrait trait_: Shape, local: true, missing: [SIDES, area], overridden: [name], unknown: [perimeter], macros: false
+naked: true
+---
+trait Shape {
+    const SIDES: u32;
+    fn area(&self) -> f64;
+    fn name(&self) -> String {
+        String::from("shape")
+    }
+}
+impl Shape <|>for Square {
+    fn name(&self) -> String {
+        String::from("square")
+    }
+    fn perimeter(&self) -> f64 {
+        4.0 * self.0
+    }
+}
+
+---
+span: [6, 14] => [6, 17]
+item: ItemImplForTrait trait_: Iterator, local: true, missing: [], overridden: [], unknown: [], macros: false
+naked: true
+---
+trait Iterator {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+}
+struct Counter;
+impl Iterator <|>for Counter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> {
+        None
+    }
+}
+
+---
+span: [1, 23] => [1, 26]
+item: ItemImplForTrait trait_: std::fmt::Display, local: false, missing: [], overridden: [], unknown: [], macros: false
+naked: true
+---
+impl std::fmt::Display <|>for Foo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+---
+span: [4, 23] => [4, 26]
+item: ItemImplForTrait trait_: std::fmt::Display, local: false, missing: [], overridden: [], unknown: [], macros: false
+naked: true
+---
+trait Display {
+    fn show(&self);
+}
+impl std::fmt::Display <|>for Foo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+---
+span: [5, 11] => [5, 14]
+item: ItemImplForTrait trait_: Shape, local: true, missing: [], overridden: [], unknown: [], macros: true
+naked: true
+---
+trait Shape {
+    fn area(&self) -> f64;
+    fn sides(&self) -> u32;
+}
+impl Shape <|>for Square {
+    shape_methods!(4);
+}
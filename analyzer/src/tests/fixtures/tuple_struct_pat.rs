@@ -1,5 +1,12 @@
 span: [2, 4] => [2, 10]
-item: PatTupleStruct
+item: PatTupleStruct refutable: false
+---
+match x {
+    Bar<|>(A) => 1
+}
+---
+span: [2, 8] => [2, 9]
+item: PatPath name: A, of: UnitVariant, refutable: true
 ---
 match x {
     Bar(<|>A) => 1
@@ -1,17 +1,17 @@
 span: [1, 7] => [1, 15]
-item: TypeArray
+item: TypeArray len: "3", value: null, unevaluated: null
 ---
 let x: <|>[u32; 3];
 
 ---
 span: [1, 7] => [1, 15]
-item: TypeArray
+item: TypeArray len: "3", value: null, unevaluated: null
 ---
 let x: [u32; <|>3];
 
 ---
 span: [1, 7] => [1, 15]
-item: TypeArray
+item: TypeArray len: N, value: null, unevaluated: UnknownConst
 ---
 let x: [u32; <|>N];
 
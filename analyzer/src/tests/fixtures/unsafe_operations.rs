@@ -0,0 +1,113 @@
+span: [14, 4] => [14, 10]
+item: ExprUnsafe operations: [raw pointer dereference, static mut access, extern fn call, union field read, "asm!"], unresolved: false
+naked: true
+unsafe: [15, 16] => [15, 18] raw pointer dereference
+unsafe: [16, 8] => [16, 13] static mut access
+unsafe: [17, 8] => [17, 13] extern fn call
+unsafe: [19, 16] => [19, 26] union field read
+unsafe: [20, 8] => [20, 19] asm!
+---
+static mut COUNT: u32 = 0;
+extern "C" {
+    fn write(fd: i32) -> i32;
+}
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {
+    let x = 5;
+    let p = &x as *const i32;
+    let mut bits = Bits { int: 1 };
+    <|>unsafe {
+        let y = *p + 1;
+        COUNT += 1;
+        write(1);
+        bits.int = 2;
+        let f = bits.float;
+        asm!("nop");
+        unsafe {
+            let z = *p;
+        }
+    }
+}
+
+---
+span: [3, 4] => [3, 10]
+item: ExprUnsafe operations: [], unresolved: false
+naked: true
+---
+fn main() {
+    let x = 5;
+    uns<|>afe {
+        let y = x + 1;
+    }
+}
+
+---
+span: [10, 4] => [10, 10]
+item: UnsafeFn operations: [raw pointer dereference, unsafe fn call]
+naked: true
+unsafe: [12, 8] => [12, 13] raw pointer dereference
+unsafe: [12, 21] => [12, 24] unsafe fn call
+---
+struct Buffer {
+    data: *mut u8,
+}
+
+impl Buffer {
+    unsafe fn get(&self, index: usize) -> u8 {
+        *self.data.add(index)
+    }
+
+    <|>unsafe fn first(&self, raw: *const u8) -> u8 {
+        let next = raw.add(1);
+        *next + self.get(0)
+    }
+}
+
+---
+span: [9, 4] => [9, 10]
+item: ExprUnsafe operations: [], unresolved: true
+naked: true
+---
+struct Buffer;
+
+impl Buffer {
+    unsafe fn get(&self, index: usize) {}
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    <|>unsafe {
+        let first = v.get(0);
+    }
+}
+
+---
+span: [4, 4] => [4, 10]
+item: ExprUnsafe operations: [raw pointer dereference], unresolved: false
+naked: true
+unsafe: [5, 23] => [5, 25] raw pointer dereference
+---
+fn main() {
+    let x = 5;
+    let p = &x as *const i32;
+    <|>unsafe {
+        println!("{}", *p);
+    }
+}
+
+---
+span: [3, 4] => [3, 10]
+item: ExprUnsafe operations: [raw pointer dereference], unresolved: false
+naked: true
+unsafe: [4, 30] => [4, 32] raw pointer dereference
+---
+fn main() {
+    let p = &3usize as *const usize;
+    <|>unsafe {
+        let zeros = vec![0u8; *p];
+    }
+}
@@ -40,3 +40,54 @@ span: [1, 0] => [1, 13]
 item: VisRestricted path: Crate, in_: true
 ---
 pub(in <|>crate) type A = B;
+
+---
+span: [3, 8] => [3, 11]
+item: VisPublic field: false, visibility: {path: "crate::net::tcp::Conn", reachable: "crate::net", limited_by: "crate::net::tcp"}
+naked: true
+---
+pub mod net {
+    mod tcp {
+        pub<|> struct Conn;
+    }
+}
+
+---
+span: [3, 8] => [3, 18]
+item: VisRestricted path: Super, in_: false, visibility: {path: "crate::net::tcp::connect", reachable: "crate::net", limited_by: null}
+naked: true
+---
+pub mod net {
+    pub mod tcp {
+        pub(<|>super) fn connect() {}
+    }
+}
+
+---
+span: [3, 8] => [3, 26]
+item: VisRestricted path: Path, in_: true, visibility: {path: "crate::net::tcp::Conn", reachable: "crate::net", limited_by: null}
+naked: true
+---
+pub mod net {
+    pub mod tcp {
+        pub(in crate::<|>net) struct Conn;
+    }
+}
+
+---
+span: [2, 4] => [2, 7]
+item: VisPublic field: true, visibility: {path: null, reachable: crate, limited_by: "crate::Conn"}
+naked: true
+---
+pub(crate) struct Conn {
+    pub<|> fd: u32,
+}
+
+---
+span: [2, 4] => [2, 7]
+item: VisPublic field: false, visibility: null
+naked: true
+---
+fn main() {
+    pub<|> struct Local;
+}
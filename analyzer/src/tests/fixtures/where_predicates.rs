@@ -0,0 +1,40 @@
+span: [1, 30] => [1, 36]
+item: PredicateLifetime lifetime: "'a", bounds: ["'b"]
+naked: true
+---
+fn f<'a, 'b>(x: &'a u8) where 'a<|>: 'b {}
+
+---
+span: [1, 7] => [1, 16]
+item: PredicateLifetime lifetime: "'a", bounds: ["'b", "'c"]
+naked: true
+---
+fn f<'a:<|> 'b + 'c, 'b, 'c>() {}
+
+---
+span: [1, 19] => [1, 26]
+item: TypeLifetimeBound ty: T, lifetime: "'static", static_: true
+naked: true
+---
+fn f<T>() where T: '<|>static {}
+
+---
+span: [1, 12] => [1, 14]
+item: TypeLifetimeBound ty: T, lifetime: "'a", static_: false
+naked: true
+---
+fn f<'a, T: 'a<|>>(x: &'a T) {}
+
+---
+span: [1, 35] => [1, 44]
+item: Binding ident: Item, trait_: Iterator, ty: u8
+naked: true
+---
+fn f<I>(iter: I) where I: Iterator<It<|>em = u8> {}
+
+---
+span: [1, 25] => [1, 38]
+item: Constraint ident: Item, trait_: Iterator, bounds: Display
+naked: true
+---
+fn f(iter: impl Iterator<Item: Disp<|>lay>) {}
@@ -15,29 +15,53 @@ macro_rules! case {
 }
 
 case![arrays];
+case![associated_items];
 case![attributes];
 case![binding_patterns];
+case![binding_scopes];
 case![bound_lifetimes];
+case![calls];
+case![closure_captures];
 case![comments];
+case![const_eval];
+case![desugaring];
+case![drop_order];
+case![enum_discriminants];
 case![enums];
+case![exit_points];
 case![extern_crate];
 case![fn_type];
 case![generics];
+case![impl_blocks];
 case![inner_doc_comment];
 case![item_use];
 case![let_patterns];
 case![let_stmt];
+case![lifetime_elision];
 case![loops];
 case![macros];
+case![match_coverage];
+case![match_ergonomics];
+case![method_calls];
 case![nested_item_comment];
+case![numeric_casts];
+case![object_safety];
+case![operator_precedence];
 case![paths];
+case![pattern_forms];
+case![privacy];
 case![qself];
+case![question_mark];
 case![raw_ident];
 case![receiver];
+case![refutability];
 case![returns];
 case![struct_field];
+case![trait_impls];
 case![tuple_struct_pat];
 case![type_array];
 case![type_reference];
 case![unit];
+case![unsafe_operations];
 case![visibility];
+case![where_predicates];
@@ -36,31 +36,58 @@ struct RunData {
     naked: bool,
     expected_item: Option<HelpItem>,
     span: (Location, Location),
+    /// Only checked if present
+    desugared: Option<Option<String>>,
+    /// Only checked if present
+    scope: Option<Option<(Location, Location)>>,
+    /// Exit points with their labels, only checked if there's at least one
+    exits: Vec<((Location, Location), String)>,
+    /// Operands of a binary operator, only checked if there's at least one
+    operands: Vec<(Location, Location)>,
+    /// Operations that need `unsafe`, with their labels, only checked if there's at least one
+    unsafe_operations: Vec<((Location, Location), String)>,
+}
+
+fn parse_span(line: &str) -> (Location, Location) {
+    let span_components: Vec<_> = line.trim().split("=>").collect();
+    assert_eq!(span_components.len(), 2);
+    let start: [usize; 2] = serde_yaml::from_str(span_components[0].trim()).expect("span format");
+    let end: [usize; 2] = serde_yaml::from_str(span_components[1].trim()).expect("span format");
+    (
+        Location {
+            line: start[0],
+            column: start[1],
+        },
+        Location {
+            line: end[0],
+            column: end[1],
+        },
+    )
+}
+
+/// A span followed by a label, like `[1, 4] => [1, 10] return`
+fn parse_labeled_span(line: &str) -> ((Location, Location), String) {
+    let line = line.trim();
+    let label_start = line.rfind(']').expect("labeled span format") + 1;
+    (
+        parse_span(&line[..label_start]),
+        line[label_start..].trim().to_string(),
+    )
 }
 
 fn parse_run_data(lines: &[&str]) -> RunData {
     let mut item: Result<Option<HelpItem>, ()> = Err(());
     let mut span = None;
     let mut naked = false;
+    let mut desugared = None;
+    let mut scope = None;
+    let mut exits = vec![];
+    let mut operands = vec![];
+    let mut unsafe_operations = vec![];
 
     for line in lines {
         if line.starts_with("span:") {
-            let span_components: Vec<_> = line[("span:".len())..].trim().split("=>").collect();
-            assert_eq!(span_components.len(), 2);
-            let start: [usize; 2] =
-                serde_yaml::from_str(span_components[0].trim()).expect("span format");
-            let end: [usize; 2] =
-                serde_yaml::from_str(span_components[1].trim()).expect("span format");
-            span = Some((
-                Location {
-                    line: start[0],
-                    column: start[1],
-                },
-                Location {
-                    line: end[0],
-                    column: end[1],
-                },
-            ));
+            span = Some(parse_span(&line["span:".len()..]));
             continue;
         }
         if line.starts_with("item:") {
@@ -85,6 +112,32 @@ fn parse_run_data(lines: &[&str]) -> RunData {
             naked = bool::from_str(naked_line).expect("naked should be boolean");
             continue;
         }
+        if line.starts_with("desugared:") {
+            let desugared_line = &line["desugared:".len()..].trim();
+            desugared = Some(serde_yaml::from_str(desugared_line).expect("desugared format"));
+            continue;
+        }
+        if line.starts_with("exit:") {
+            exits.push(parse_labeled_span(&line["exit:".len()..]));
+            continue;
+        }
+        if line.starts_with("unsafe:") {
+            unsafe_operations.push(parse_labeled_span(&line["unsafe:".len()..]));
+            continue;
+        }
+        if line.starts_with("operand:") {
+            operands.push(parse_span(&line["operand:".len()..]));
+            continue;
+        }
+        if line.starts_with("scope:") {
+            let scope_line = line["scope:".len()..].trim();
+            scope = Some(if scope_line == "null" {
+                None
+            } else {
+                Some(parse_span(scope_line))
+            });
+            continue;
+        }
         panic!("Unknown directive {:?}", line);
     }
 
@@ -92,6 +145,11 @@ fn parse_run_data(lines: &[&str]) -> RunData {
         expected_item: item.expect("item should be defined"),
         span: span.expect("span should be defined"),
         naked,
+        desugared,
+        scope,
+        exits,
+        operands,
+        unsafe_operations,
     }
 }
 
@@ -132,10 +190,11 @@ fn run_case(code: &[&str], run_data: RunData, case: usize) {
 
     let analyzer = ir_visitor.visit();
 
-    let result = analyzer.analyze(Location {
+    let location = Location {
         line: line + 1 + offset,
         column,
-    });
+    };
+    let result = analyzer.analyze(location);
 
     assert_eq!(
         run_data.expected_item.as_ref(),
@@ -150,16 +209,63 @@ fn run_case(code: &[&str], run_data: RunData, case: usize) {
         return;
     };
 
-    let adjusted = (
+    let adjusted = unwrapped((result.start, result.end), offset);
+
+    assert_eq!(run_data.span, adjusted, "Case {}", case);
+
+    if let Some(desugared) = run_data.desugared {
+        assert_eq!(desugared, result.desugared, "Case {}", case);
+    }
+
+    if let Some(scope) = run_data.scope {
+        let adjusted = analyzer
+            .binding_scope(location)
+            .map(|range| unwrapped(range, offset));
+        assert_eq!(scope, adjusted, "Case {}", case);
+    }
+
+    if !run_data.exits.is_empty() {
+        let exits: Vec<_> = analyzer
+            .exit_points(location)
+            .into_iter()
+            .map(|exit| (unwrapped(exit.range, offset), exit.label))
+            .collect();
+        assert_eq!(run_data.exits, exits, "Case {}", case);
+    }
+
+    if !run_data.operands.is_empty() {
+        let operands: Vec<_> = analyzer
+            .operands(location)
+            .into_iter()
+            .map(|range| unwrapped(range, offset))
+            .collect();
+        assert_eq!(run_data.operands, operands, "Case {}", case);
+    }
+
+    if !run_data.unsafe_operations.is_empty() {
+        let unsafe_operations: Vec<_> = analyzer
+            .unsafe_operations(location)
+            .into_iter()
+            .map(|operation| (unwrapped(operation.range, offset), operation.label))
+            .collect();
+        assert_eq!(
+            run_data.unsafe_operations, unsafe_operations,
+            "Case {}",
+            case
+        );
+    }
+}
+
+/// Maps a range in the wrapped source back to the lines of the spec
+fn unwrapped((start, end): (Location, Location), offset: usize) -> (Location, Location) {
+    (
         Location {
-            line: result.start.line - offset,
-            ..result.start
+            line: start.line - offset,
+            ..start
         },
         Location {
-            line: result.end.line - offset,
-            ..result.end
+            line: end.line - offset,
+            ..end
         },
-    );
-
-    assert_eq!(run_data.span, adjusted, "Case {}", case);
+    )
 }
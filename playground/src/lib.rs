@@ -183,6 +183,7 @@ impl Session {
         .filter_map(|result| result)
         .map(|result| Explanation {
             item: result.help,
+            desugared: result.desugared,
             start_line: result.start.line,
             start_column: result.start.column,
             end_line: result.end.line,
@@ -217,6 +218,91 @@ impl Session {
         count
     }
 
+    /// Range where the binding at the given location can be used, as
+    /// `[start_line, start_column, end_line, end_column]`
+    #[wasm_bindgen]
+    pub fn binding_scope(&self, line: usize, column: usize) -> Option<Box<[u32]>> {
+        let location = LineColumn { line, column };
+
+        self.analyzer
+            .binding_scope(location.into())
+            .map(|(start, end)| {
+                vec![
+                    start.line as u32,
+                    start.column as u32,
+                    end.line as u32,
+                    end.column as u32,
+                ]
+                .into_boxed_slice()
+            })
+    }
+
+    /// Points where control leaves the function whose `fn` keyword or `->` is
+    /// at the given location, as a flat list of
+    /// `start_line, start_column, end_line, end_column, label`
+    #[wasm_bindgen]
+    pub fn exit_points(&self, line: usize, column: usize) -> Box<[JsValue]> {
+        let location = LineColumn { line, column };
+
+        self.analyzer
+            .exit_points(location.into())
+            .into_iter()
+            .flat_map(|exit| {
+                let (start, end) = exit.range;
+                vec![
+                    JsValue::from(start.line as u32),
+                    JsValue::from(start.column as u32),
+                    JsValue::from(end.line as u32),
+                    JsValue::from(end.column as u32),
+                    JsValue::from(exit.label),
+                ]
+            })
+            .collect::<Box<[_]>>()
+    }
+
+    /// Operations that need the `unsafe` block or `unsafe fn` whose `unsafe`
+    /// keyword is at the given location, as a flat list of
+    /// `start_line, start_column, end_line, end_column, label`
+    #[wasm_bindgen]
+    pub fn unsafe_operations(&self, line: usize, column: usize) -> Box<[JsValue]> {
+        let location = LineColumn { line, column };
+
+        self.analyzer
+            .unsafe_operations(location.into())
+            .into_iter()
+            .flat_map(|operation| {
+                let (start, end) = operation.range;
+                vec![
+                    JsValue::from(start.line as u32),
+                    JsValue::from(start.column as u32),
+                    JsValue::from(end.line as u32),
+                    JsValue::from(end.column as u32),
+                    JsValue::from(operation.label),
+                ]
+            })
+            .collect::<Box<[_]>>()
+    }
+
+    /// Operands of the binary operator at the given location, as a flat list of
+    /// `start_line, start_column, end_line, end_column`
+    #[wasm_bindgen]
+    pub fn operands(&self, line: usize, column: usize) -> Box<[u32]> {
+        let location = LineColumn { line, column };
+
+        self.analyzer
+            .operands(location.into())
+            .into_iter()
+            .flat_map(|(start, end)| {
+                vec![
+                    start.line as u32,
+                    start.column as u32,
+                    end.line as u32,
+                    end.column as u32,
+                ]
+            })
+            .collect::<Box<[_]>>()
+    }
+
     #[wasm_bindgen]
     pub fn explain(&self, line: usize, column: usize) -> Option<Explanation> {
         let location = LineColumn { line, column };
@@ -225,6 +311,7 @@ impl Session {
             .analyze(location.into())
             .map(|result| Explanation {
                 item: result.help,
+                desugared: result.desugared,
                 start_line: result.start.line,
                 start_column: result.start.column,
                 end_line: result.end.line,
@@ -237,6 +324,7 @@ impl Session {
 #[cfg_attr(feature = "dev", derive(Debug))]
 pub struct Explanation {
     item: HelpItem,
+    desugared: Option<String>,
     pub start_line: usize,
     pub start_column: usize,
     pub end_line: usize,
@@ -253,6 +341,10 @@ impl Explanation {
         self.item.title().into()
     }
 
+    pub fn desugared(&self) -> JsValue {
+        self.desugared.clone().into()
+    }
+
     pub fn info(&self) -> Box<[JsValue]> {
         self.item
             .info()
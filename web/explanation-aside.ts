@@ -127,6 +127,10 @@ function session() {
     ".error-message-container"
   )!!;
   const errorMessageEl = itemContainer.querySelector(".error-message")!!;
+  const desugaredContainer = itemContainer.querySelector(
+    ".desugared-container"
+  )!!;
+  const desugaredEl = itemContainer.querySelector(".desugared")!!;
 
   const fileBugEl = explanationEl.querySelector(".file-bug")!!;
   const doFileBugLink = explanationEl.querySelector(".do-file-bug")!!;
@@ -206,6 +210,13 @@ function session() {
       "shake"
     );
 
+    setDisplay(
+      desugaredContainer,
+      compilationState !== ERROR && elaboration?.desugared != null
+        ? "block"
+        : "none"
+    );
+
     if (compilationState === ERROR) {
       setHtml(itemTitle, "Oops! 💥");
       setHtml(itemEl, "There is a syntax error in your code:");
@@ -216,6 +227,7 @@ function session() {
       setHtml(itemTitle, elaboration.title);
       setHtml(itemEl, elaboration.elaboration);
       setDisplay(errorMessageContainer, "none");
+      setText(desugaredEl, elaboration.desugared ?? "");
     } else {
       setHtml(itemTitle, initialItemTitle);
       setHtml(itemEl, initialItem);
@@ -141,6 +141,11 @@ export type Elaboration = {
   location: Span;
   title: string;
   elaboration: string;
+  desugared: string | null;
+  scope: Span | null;
+  exits: { location: Span; label: string }[];
+  unsafeOperations: { location: Span; label: string }[];
+  operands: Span[];
   extraInfo: { link: string; kind: string }[];
 };
 
@@ -188,6 +193,10 @@ const initialCompilation = state.compilation;
 type NonUIState = {
   rules: number;
   mark: TextMarker | null;
+  scopeMark: TextMarker | null;
+  exitMarks: TextMarker[];
+  unsafeMarks: TextMarker[];
+  operandMarks: TextMarker[];
   hoverMark: TextMarker | null;
   computedMarks: TextMarker[] | null;
   errorMark: TextMarker | null;
@@ -202,6 +211,10 @@ type NonUIState = {
 let nonUiState: NonUIState = {
   rules: 0,
   mark: null,
+  scopeMark: null,
+  exitMarks: [],
+  unsafeMarks: [],
+  operandMarks: [],
   hoverMark: null,
   computedMarks: null,
   errorMark: null,
@@ -665,9 +678,36 @@ const renderElaborationMark = pure(function renderElaborationMark({
   elaboration: Elaboration | null;
 }) {
   nonUiState.mark && nonUiState.mark.clear();
+  nonUiState.scopeMark && nonUiState.scopeMark.clear();
+  nonUiState.scopeMark = null;
+  nonUiState.exitMarks.forEach((mark) => mark.clear());
+  nonUiState.exitMarks = [];
+  nonUiState.unsafeMarks.forEach((mark) => mark.clear());
+  nonUiState.unsafeMarks = [];
+  nonUiState.operandMarks.forEach((mark) => mark.clear());
+  nonUiState.operandMarks = [];
 
   if (elaboration != null) {
     nonUiState.mark = getMark(elaboration.location);
+    if (elaboration.scope != null) {
+      nonUiState.scopeMark = getMark(elaboration.scope, "scope-highlighted");
+    }
+    nonUiState.exitMarks = elaboration.exits.map(({ location, label }) =>
+      cm.markText(location.start, location.end, {
+        className: "exit-highlighted",
+        title: label,
+      })
+    );
+    nonUiState.unsafeMarks = elaboration.unsafeOperations.map(
+      ({ location, label }) =>
+        cm.markText(location.start, location.end, {
+          className: "unsafe-highlighted",
+          title: label,
+        })
+    );
+    nonUiState.operandMarks = elaboration.operands.map((operand) =>
+      getMark(operand, "operand-highlighted")
+    );
   }
 });
 
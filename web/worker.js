@@ -188,7 +188,7 @@ function computeHitbox(location) {
 /* Main worker */
 function elaborate(location) {
   explain(location);
-  notifyElaboration();
+  notifyElaboration(location);
 }
 
 /* Main worker */
@@ -211,8 +211,11 @@ function notifyHitbox() {
 }
 
 /* Main worker */
-function notifyElaboration() {
+function notifyElaboration(location) {
   const elaboration = state.explanation && state.explanation.elaborate();
+  const scope =
+    elaboration &&
+    state.session.binding_scope(location.line + 1, location.ch);
 
   postMessage({
     type: messages.ELABORATION,
@@ -220,9 +223,60 @@ function notifyElaboration() {
     elaboration,
     extraInfo: elaboration && computeExtraInfo(state.explanation.info()),
     title: elaboration && state.explanation.title(),
+    desugared: elaboration && state.explanation.desugared(),
+    scope: scope ? scopeLocation(scope) : null,
+    exits: elaboration
+      ? computeLabeledSpans(
+          state.session.exit_points(location.line + 1, location.ch)
+        )
+      : [],
+    unsafeOperations: elaboration
+      ? computeLabeledSpans(
+          state.session.unsafe_operations(location.line + 1, location.ch)
+        )
+      : [],
+    operands: elaboration
+      ? computeOperands(
+          state.session.operands(location.line + 1, location.ch)
+        )
+      : [],
   });
 }
 
+function computeLabeledSpans(spans) {
+  let ret = [];
+  for (let i = 0; i + 4 < spans.length; i += 5) {
+    ret.push({
+      location: scopeLocation(spans.slice(i, i + 4)),
+      label: spans[i + 4],
+    });
+  }
+
+  return ret;
+}
+
+function computeOperands(operands) {
+  let ret = [];
+  for (let i = 0; i + 3 < operands.length; i += 4) {
+    ret.push(scopeLocation(operands.slice(i, i + 4)));
+  }
+
+  return ret;
+}
+
+function scopeLocation(scope) {
+  return {
+    start: {
+      line: scope[0] - 1,
+      ch: scope[1],
+    },
+    end: {
+      line: scope[2] - 1,
+      ch: scope[3],
+    },
+  };
+}
+
 function explanationLocation(explanation) {
   return explanation != null
     ? {